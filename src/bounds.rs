@@ -1,4 +1,7 @@
-use std::{cmp::Ordering, ops::Bound};
+use std::{
+    cmp::Ordering,
+    ops::{Bound, RangeBounds},
+};
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub enum BoundSide {
@@ -68,6 +71,24 @@ pub fn partial_cmp_bounds<Idx: PartialOrd>(
     }
 }
 
+/// Get the smallest of two start bounds
+pub fn min_start<'a, Idx: PartialOrd>(a: Bound<&'a Idx>, b: Bound<&'a Idx>) -> Bound<&'a Idx> {
+    if partial_cmp_bounds(&b, BoundSide::Start, &a, BoundSide::Start) == Some(Ordering::Less) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Get the largest of two end bounds
+pub fn max_end<'a, Idx: PartialOrd>(a: Bound<&'a Idx>, b: Bound<&'a Idx>) -> Bound<&'a Idx> {
+    if partial_cmp_bounds(&b, BoundSide::End, &a, BoundSide::End) == Some(Ordering::Greater) {
+        b
+    } else {
+        a
+    }
+}
+
 /// Get the Internal value of a bound or panics if [Unbounded][Bound::Unbounded].
 pub fn expect_bound<'a, Idx>(bound: Option<Bound<&'a Idx>>, msg: &'static str) -> &'a Idx {
     match bound.expect(msg) {
@@ -87,3 +108,90 @@ pub fn reverse_bound<Idx>(bound: Bound<&Idx>) -> Bound<&Idx> {
         Bound::Unbounded => Bound::Unbounded,
     }
 }
+
+/// Check if an end bound and the start bound of a range after it are adjacent: they share the same value with one of
+/// them inclusive and the other exclusive, so that no value exists between them and they together cover the value.
+pub fn are_adjacent<Idx: PartialEq>(end: &Bound<&Idx>, start: &Bound<&Idx>) -> bool {
+    match (end, start) {
+        (Bound::Included(end), Bound::Excluded(start))
+        | (Bound::Excluded(end), Bound::Included(start)) => end == start,
+        _ => false,
+    }
+}
+
+/// The bounds of a non-empty [`crate::ContinuousRange`], implementing [`RangeBounds`].
+///
+/// Created by [`crate::ContinuousRange::try_as_range_bounds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContinuousRangeBounds<'a, Idx> {
+    start: Bound<&'a Idx>,
+    end: Bound<&'a Idx>,
+}
+
+impl<'a, Idx> ContinuousRangeBounds<'a, Idx> {
+    pub(crate) fn new(start: Bound<&'a Idx>, end: Bound<&'a Idx>) -> Self {
+        Self { start, end }
+    }
+
+    /// Clone the values of the bounds, the result can be used to index slices or [`str`]
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::end_exclusive(1, 3);
+    /// let bounds = range.try_as_range_bounds().unwrap();
+    /// assert_eq!(&[0, 1, 2, 3][bounds.cloned()], &[1, 2]);
+    /// ```
+    #[must_use]
+    pub fn cloned(self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Clone,
+    {
+        (self.start.cloned(), self.end.cloned())
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for ContinuousRangeBounds<'_, Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        self.start
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        self.end
+    }
+}
+
+/// A start bound ordered like [`partial_cmp_bounds`] orders start bounds, to be used as a key of ordered collections
+///
+/// For the same value [`Bound::Included`] is before [`Bound::Excluded`] and [`Bound::Unbounded`] is before everything.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StartBound<Idx>(pub Bound<Idx>);
+
+impl<Idx: Ord> Ord for StartBound<Idx> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        partial_cmp_bounds(
+            &self.0.as_ref(),
+            BoundSide::Start,
+            &other.0.as_ref(),
+            BoundSide::Start,
+        )
+        .expect("Bounds of totally ordered values are always comparable")
+    }
+}
+
+impl<Idx: Ord> PartialOrd for StartBound<Idx> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Check if a range ending at `end` overlaps a range starting at `start`, the first range starting before the second
+pub fn end_overlaps_start<Idx: PartialOrd>(end: Bound<&Idx>, start: Bound<&Idx>) -> bool {
+    partial_cmp_bounds(&end, BoundSide::End, &start, BoundSide::Start) != Some(Ordering::Less)
+}
+
+/// Check if a range ending at `end` overlaps or is adjacent to a range starting at `start`, the first range starting
+/// before the second, meaning that their union is continuous.
+pub fn end_touches_start<Idx: PartialOrd>(end: Bound<&Idx>, start: Bound<&Idx>) -> bool {
+    end_overlaps_start(end, start) || are_adjacent(&end, &start)
+}
@@ -3,13 +3,25 @@ use std::{
     cmp::Ordering,
     fmt,
     ops::{self, Bound},
+    str::FromStr,
 };
 
-use crate::{bounds::{expect_bound, partial_cmp_bounds, reverse_bound, BoundSide}, RangesRelation};
+use crate::{
+    bounds::{
+        are_adjacent, expect_bound, partial_cmp_bounds, reverse_bound, BoundSide,
+        ContinuousRangeBounds,
+    },
+    discrete::inclusive_bounds,
+    notation::parse_postgresql_range,
+    ContinuousRangeIter, Discrete, GapsIter, ParseRangeError, Range, RangeDisplay, RangesRelation,
+};
 
 /// A continuous range contain can be empty, contains all elements from a start and an end point of the generic
 /// parameter `Idx` or all possible values in `Idx` range.
 /// But it can't have "holes" contrary to [`crate::Range`]
+///
+/// Equality compares how ranges are written: for a [`Discrete`] type `[1..5]` and `[1..6)` contain the same values
+/// but aren't equal. Compare them with [`ContinuousRange::discrete_eq`] or after [`ContinuousRange::canonicalize`].
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Hash, PartialEq,Default)]
 pub enum ContinuousRange<Idx> {
@@ -68,6 +80,59 @@ pub enum ContinuousRange<Idx> {
     Full,
 }
 
+impl<Idx> ContinuousRange<Idx> {
+    /// Get the bounds of the range or [None] if empty
+    #[must_use]
+    pub fn range_bounds(&self) -> Option<(Bound<&Idx>, Bound<&Idx>)> {
+        match self {
+            Self::Empty => {
+                // We can't implement RangeBounds due to this case, a possible trick would be to use a range with 2
+                // exclusive bounds on the default value.
+                // But as the result is a reference we would need a per-generic 'static to reference and so would
+                // require something like the 'typemap' crate just for that.
+                // `try_as_range_bounds` provides an implementation for non-empty ranges instead.
+                None
+            }
+            Self::Single(value) => Some((Bound::Included(value), Bound::Included(value))),
+            Self::Inclusive(start, end) => Some((Bound::Included(start), Bound::Included(end))),
+            Self::Exclusive(start, end) => Some((Bound::Excluded(start), Bound::Excluded(end))),
+            Self::StartExclusive(start, end) => {
+                Some((Bound::Excluded(start), Bound::Included(end)))
+            }
+            Self::EndExclusive(start, end) => Some((Bound::Included(start), Bound::Excluded(end))),
+            Self::From(start) => Some((Bound::Included(start), Bound::Unbounded)),
+            Self::FromExclusive(start) => Some((Bound::Excluded(start), Bound::Unbounded)),
+            Self::To(end) => Some((Bound::Unbounded, Bound::Included(end))),
+            Self::ToExclusive(end) => Some((Bound::Unbounded, Bound::Excluded(end))),
+            Self::Full => Some((Bound::Unbounded, Bound::Unbounded)),
+        }
+    }
+}
+
+impl<Idx: PartialOrd> ContinuousRange<Idx> {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Empty => true,
+
+            // bounded ranges with inverted bounds are considered empty
+            Self::Inclusive(start, end) => start > end,
+            Self::Exclusive(start, end)
+            | Self::StartExclusive(start, end)
+            | Self::EndExclusive(start, end) => start >= end,
+
+            Self::Single(_)
+
+            // unbounded ranges can't be empty
+            | Self::From(_)
+            | Self::FromExclusive(_)
+            | Self::To(_)
+            | Self::ToExclusive(_)
+            | Self::Full => false,
+        }
+    }
+}
+
 impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
     /// A range containing no value
     ///
@@ -209,18 +274,30 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         }
     }
 
-    /// Get the bounds of the range or [None] if empty
+    /// Create a new range from any [`ops::RangeBounds`] implementation, like the std range types or a tuple of
+    /// [`Bound`]
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!(ContinuousRange::from_range_bounds(1..=5), ContinuousRange::inclusive(1, 5));
+    /// assert_eq!(
+    ///     ContinuousRange::from_range_bounds((Bound::Excluded(1), Bound::Unbounded)),
+    ///     ContinuousRange::from_exclusive(1)
+    /// );
+    /// ```
     #[must_use]
-    pub fn range_bounds(&self) -> Option<(Bound<&Idx>, Bound<&Idx>)> {
+    pub fn from_range_bounds(bounds: impl ops::RangeBounds<Idx>) -> Self {
+        Self::from_bounds((bounds.start_bound(), bounds.end_bound()))
+    }
+
+    /// Convert the range into its bounds or [None] if empty
+    #[must_use]
+    pub fn into_bounds(self) -> Option<(Bound<Idx>, Bound<Idx>)> {
         match self {
-            Self::Empty => {
-                // We can't implement RangeBounds due to this case, a possible trick would be to use a range with 2
-                // exclusive bounds on the default value.
-                // But as the result is a reference we would need a per-generic 'static to reference and so would
-                // require something like the 'typemap' crate just for that.
-                None
-            }
-            Self::Single(value) => Some((Bound::Included(value), Bound::Included(value))),
+            Self::Empty => None,
+            Self::Single(value) => Some((Bound::Included(value.clone()), Bound::Included(value))),
             Self::Inclusive(start, end) => Some((Bound::Included(start), Bound::Included(end))),
             Self::Exclusive(start, end) => Some((Bound::Excluded(start), Bound::Excluded(end))),
             Self::StartExclusive(start, end) => {
@@ -235,6 +312,31 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         }
     }
 
+    /// Get the bounds of the range as a [`ops::RangeBounds`] implementation or [None] if the range is empty
+    ///
+    /// This allows querying standard collections, for example with [`std::collections::BTreeMap::range`] or
+    /// [`Vec::drain`]. Ranges with inverted bounds are considered empty, so the result never makes these methods
+    /// panic due to the order of the bounds.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map: BTreeMap<_, _> = (0..10).map(|i| (i, i * 10)).collect();
+    /// let range = ContinuousRange::start_exclusive(2, 5);
+    /// let values: Vec<_> = map.range(range.try_as_range_bounds().unwrap()).map(|(_, v)| *v).collect();
+    /// assert_eq!(values, vec![30, 40, 50]);
+    /// ```
+    #[must_use]
+    pub fn try_as_range_bounds(&self) -> Option<ContinuousRangeBounds<'_, Idx>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.range_bounds()
+            .map(|(start, end)| ContinuousRangeBounds::new(start, end))
+    }
+
     #[must_use]
     pub fn start(&self) -> Option<Bound<&Idx>> {
         match self {
@@ -314,6 +416,10 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         self.compare(other).map_or(true, |r| r.disjoint())
     }
 
+    /// Union of two ranges or [None] if the result isn't continuous
+    ///
+    /// Bounds are compared as is, so for a [`Discrete`] type `[1..3]` and `[4..6]` can't be merged even if no value
+    /// exists between them. Use [`ContinuousRange::discrete_union`] to merge them.
     #[must_use]
     #[allow(clippy::missing_panics_doc)]
     pub fn union(&self, other: &ContinuousRange<Idx>) -> Option<ContinuousRange<Idx>>
@@ -325,8 +431,18 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
             (ContinuousRange::Full, _) | (_, ContinuousRange::Full) => Some(ContinuousRange::Full),
             _ => match self.compare(other) {
                 Some(cmp) => match cmp {
-                    RangesRelation::StrictlyBefore => None,
-                    RangesRelation::StrictlyAfter => None,
+                    RangesRelation::StrictlyBefore => {
+                        let (start, end) =
+                            self.range_bounds().expect("Self is before without bounds");
+                        let (other_start, other_end) =
+                            other.range_bounds().expect("Other is after without bounds");
+                        if are_adjacent(&end, &other_start) {
+                            Some(ContinuousRange::from_bounds((start, other_end)))
+                        } else {
+                            None
+                        }
+                    }
+                    RangesRelation::StrictlyAfter => other.union(self),
                     RangesRelation::Meets => {
                         let start = self.start().expect("Self meets without bounds");
                         let end = other.end().expect("Other meets without bounds");
@@ -416,8 +532,8 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         Idx: PartialOrd + std::fmt::Debug,
     {
         match (self, other) {
-            (ContinuousRange::Empty, r) => Some(r.clone()),
-            (_, ContinuousRange::Empty) => Some(ContinuousRange::Empty),
+            (ContinuousRange::Empty, _) => Some(ContinuousRange::Empty),
+            (r, ContinuousRange::Empty) => Some(r.clone()),
             _ => match self.compare(other) {
                 Some(cmp) => match cmp {
                     RangesRelation::StrictlyBefore => Some(self.clone()),
@@ -468,6 +584,45 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         }
     }
 
+    /// Iterate over the parts of the range that aren't covered by any of `ranges`, in order
+    ///
+    /// `ranges` must be sorted by start bound, they can overlap each other and extend past the range. Empty ranges are
+    /// ignored. Use [`Range::gaps`] when the covering ranges aren't sorted.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let day = ContinuousRange::end_exclusive(9, 18);
+    /// let meetings = [
+    ///     ContinuousRange::end_exclusive(8, 10),
+    ///     ContinuousRange::end_exclusive(12, 14),
+    ///     ContinuousRange::end_exclusive(13, 15),
+    /// ];
+    /// let free: Vec<_> = day.gaps(&meetings).collect();
+    /// assert_eq!(
+    ///     free,
+    ///     vec![
+    ///         ContinuousRange::end_exclusive(10, 12),
+    ///         ContinuousRange::end_exclusive(15, 18),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn gaps<'a>(&self, ranges: &'a [ContinuousRange<Idx>]) -> GapsIter<'a, Idx> {
+        GapsIter::new(self, ranges)
+    }
+
+    /// Symmetric difference of two ranges, containing the values present in exactly one of them
+    ///
+    /// The result is a [`Range`] as it might not be continuous: `[0..10] ^ [5..15] = {[0..5); (10..15]}`
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &ContinuousRange<Idx>) -> Range<Idx>
+    where
+        Idx: PartialOrd + std::fmt::Debug,
+    {
+        Range::Continuous(self.clone()).symmetric_difference(&Range::Continuous(other.clone()))
+    }
+
     #[must_use]
     pub fn intersects(&self, other: &ContinuousRange<Idx>) -> bool
     where
@@ -641,25 +796,122 @@ No ordering can be found between {self:?} and {other:?}",
         clone
     }
 
+    /// Convert a range over a [`Discrete`] type to its canonical `[start..end)` form, like PostgreSQL does for its
+    /// discrete range types.
+    ///
+    /// `[1..5]`, `(0..5]` and `(0..6)` all become `[1..6)`, so that equivalent ranges compare equal and ranges
+    /// following each other like `[1..3]` and `[4..6]` can be merged by [`ContinuousRange::union`].
+    ///
+    /// Ranges ending on the largest value of `Idx` keep an inclusive end as no exclusive end can be expressed.
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        match self {
-            Self::Empty => true,
+    pub fn canonicalize(&self) -> Self
+    where
+        Idx: Discrete,
+    {
+        let Some((start, end)) = self.range_bounds() else {
+            return Self::Empty;
+        };
+
+        let start = match start {
+            Bound::Included(start) => Bound::Included(start.clone()),
+            Bound::Excluded(start) => match start.successor() {
+                Some(start) => Bound::Included(start),
+                None => return Self::Empty,
+            },
+            Bound::Unbounded => Bound::Unbounded,
+        };
 
-            // bounded ranges with inverted bounds are considered empty
-            Self::Inclusive(start, end) => start > end,
-            Self::Exclusive(start, end)
-            | Self::StartExclusive(start, end)
-            | Self::EndExclusive(start, end) => start >= end,
+        let end = match end {
+            Bound::Included(end) => match end.successor() {
+                Some(end) => Bound::Excluded(end),
+                None => Bound::Included(end.clone()),
+            },
+            Bound::Excluded(end) => Bound::Excluded(end.clone()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
 
-            Self::Single(_)
+        Self::from_bounds((start.as_ref(), end.as_ref()))
+    }
 
-            // unbounded ranges can't be empty
-            | Self::From(_)
-            | Self::FromExclusive(_)
-            | Self::To(_)
-            | Self::ToExclusive(_)
-            | Self::Full => false,
+    pub fn canonicalize_mut(&mut self)
+    where
+        Idx: Discrete,
+    {
+        *self = self.canonicalize();
+    }
+
+    /// Union of two ranges over a [`Discrete`] type in canonical form, or [None] if the result isn't continuous
+    ///
+    /// Contrary to [`ContinuousRange::union`], ranges following each other with no value between them are merged:
+    /// `[1..3]` and `[4..6]` give `[1..7)`.
+    #[must_use]
+    pub fn discrete_union(&self, other: &ContinuousRange<Idx>) -> Option<ContinuousRange<Idx>>
+    where
+        Idx: Discrete + std::fmt::Debug,
+    {
+        self.canonicalize().union(&other.canonicalize())
+    }
+
+    /// Check if two ranges over a [`Discrete`] type contain the same values
+    ///
+    /// `==` compares how the ranges are written, so `[1..5] == [1..6)` is false while they are equal here.
+    #[must_use]
+    pub fn discrete_eq(&self, other: &ContinuousRange<Idx>) -> bool
+    where
+        Idx: Discrete,
+    {
+        self.canonicalize() == other.canonicalize()
+    }
+
+    /// Iterate over all the values of a range of [`Discrete`] values, in order
+    ///
+    /// Iterating forward over a range without start bound or backward over a range without end bound panics.
+    #[must_use]
+    pub fn iter(&self) -> ContinuousRangeIter<Idx>
+    where
+        Idx: Discrete,
+    {
+        ContinuousRangeIter::new(self)
+    }
+
+    /// Number of values contained in a range of [`Discrete`] values
+    ///
+    /// Returns [None] if the range is unbounded or if the number of values doesn't fit in an [`u128`].
+    #[must_use]
+    pub fn count(&self) -> Option<u128>
+    where
+        Idx: Discrete,
+    {
+        match inclusive_bounds(self) {
+            Some((Bound::Included(start), Bound::Included(end))) => {
+                Idx::steps_between(&start, &end)?.checked_add(1)
+            }
+            Some(_) => None,
+            None => Some(0),
+        }
+    }
+
+    /// Measure of the range, the difference between its end and its start
+    ///
+    /// Empty ranges and single values have a measure of [zero][Default::default] and unbounded ranges have no
+    /// measure. Whether the bounds are inclusive or exclusive doesn't change the measure: `[1.0..2.5)` and
+    /// `[1.0..2.5]` both measure `1.5`.
+    #[must_use]
+    pub fn measure<M>(&self) -> Option<M>
+    where
+        Idx: ops::Sub<Output = M>,
+        M: Default,
+    {
+        if self.is_empty() {
+            return Some(M::default());
+        }
+
+        match self.range_bounds()? {
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) => Some(end.clone() - start.clone()),
+            _ => None,
         }
     }
 
@@ -667,6 +919,39 @@ No ordering can be found between {self:?} and {other:?}",
     pub fn is_full(&self) -> bool {
         matches!(self, Self::Full)
     }
+
+    /// Format the range using a selectable [`crate::Notation`], the [`fmt::Display`] implementation uses
+    /// [`crate::Notation::Native`]
+    #[must_use]
+    pub fn display(&self) -> RangeDisplay<'_, Self>
+    where
+        Idx: fmt::Display,
+    {
+        RangeDisplay::new(self)
+    }
+
+    /// Parse a PostgreSQL range literal like `[1,5)`, `(,3]` or `empty`
+    ///
+    /// This is the format of range types like `int4range` or `tstzrange` in PostgreSQL text output, formatting a
+    /// range with [`crate::Notation::PostgreSql`] produces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseRangeError`] with the position and cause of the failure if the input isn't a valid range
+    /// literal or if one of its values can't be parsed.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::<i32>::parse_postgresql("[1,5)").unwrap();
+    /// assert_eq!(range, ContinuousRange::end_exclusive(1, 5));
+    /// ```
+    pub fn parse_postgresql(input: &str) -> Result<Self, ParseRangeError<Idx::Err>>
+    where
+        Idx: FromStr,
+    {
+        parse_postgresql_range(input)
+    }
 }
 
 impl<Idx: PartialOrd + Clone> From<()> for ContinuousRange<Idx> {
@@ -675,6 +960,12 @@ impl<Idx: PartialOrd + Clone> From<()> for ContinuousRange<Idx> {
     }
 }
 
+impl<Idx: PartialOrd + Clone> From<(Bound<Idx>, Bound<Idx>)> for ContinuousRange<Idx> {
+    fn from((start, end): (Bound<Idx>, Bound<Idx>)) -> Self {
+        Self::from_bounds((start.as_ref(), end.as_ref()))
+    }
+}
+
 impl<Idx: PartialOrd + Clone> From<ops::RangeFull> for ContinuousRange<Idx> {
     fn from(_: ops::RangeFull) -> Self {
         Self::full()
@@ -774,3 +1065,9 @@ impl<Idx: fmt::Debug> fmt::Debug for ContinuousRange<Idx> {
         Ok(())
     }
 }
+
+impl<Idx: fmt::Display + PartialOrd> fmt::Display for ContinuousRange<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        RangeDisplay::new(self).fmt(fmt)
+    }
+}
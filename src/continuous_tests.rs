@@ -119,6 +119,7 @@ mod test_fmt_debug {
 #[allow(clippy::reversed_empty_ranges)]
 mod test_from_stdlib {
     use assert_matches::assert_matches;
+    use std::ops::Bound;
 
     use crate::ContinuousRange;
 
@@ -193,6 +194,142 @@ mod test_from_stdlib {
         let r: ContinuousRange<i32> = (..).into();
         assert_matches!(r, ContinuousRange::Full);
     }
+
+    #[test]
+    pub fn bounds_tuple() {
+        let r: ContinuousRange<_> = (Bound::Excluded(1), Bound::Included(5)).into();
+        assert_matches!(r, ContinuousRange::StartExclusive(1, 5));
+
+        let r: ContinuousRange<_> = (Bound::Excluded(1), Bound::Unbounded).into();
+        assert_matches!(r, ContinuousRange::FromExclusive(1));
+
+        let r: ContinuousRange<i32> = (Bound::Unbounded, Bound::Unbounded).into();
+        assert_matches!(r, ContinuousRange::Full);
+    }
+
+    #[test]
+    pub fn bounds_tuple_inverted() {
+        let r: ContinuousRange<_> = (Bound::Excluded(5), Bound::Excluded(1)).into();
+        assert_matches!(r, ContinuousRange::Empty);
+    }
+
+    #[test]
+    pub fn from_range_bounds() {
+        assert_matches!(
+            ContinuousRange::from_range_bounds(1..=5),
+            ContinuousRange::Inclusive(1, 5)
+        );
+        assert_matches!(
+            ContinuousRange::from_range_bounds(..5),
+            ContinuousRange::ToExclusive(5)
+        );
+        assert_matches!(
+            ContinuousRange::<i32>::from_range_bounds(..),
+            ContinuousRange::Full
+        );
+        assert_matches!(
+            ContinuousRange::from_range_bounds((Bound::Excluded(1), Bound::Excluded(5))),
+            ContinuousRange::Exclusive(1, 5)
+        );
+        assert_matches!(
+            ContinuousRange::from_range_bounds((Bound::Included(&1), Bound::Excluded(&5))),
+            ContinuousRange::EndExclusive(1, 5)
+        );
+    }
+}
+
+mod test_into_stdlib {
+    use std::{
+        convert::TryFrom,
+        ops::{self, Bound},
+    };
+
+    use crate::{ContinuousRange, TryFromRangeError};
+
+    #[test]
+    pub fn into_bounds() {
+        assert_eq!(ContinuousRange::<i32>::empty().into_bounds(), None);
+        assert_eq!(
+            ContinuousRange::single(1).into_bounds(),
+            Some((Bound::Included(1), Bound::Included(1)))
+        );
+        assert_eq!(
+            ContinuousRange::start_exclusive(1, 5).into_bounds(),
+            Some((Bound::Excluded(1), Bound::Included(5)))
+        );
+        assert_eq!(
+            ContinuousRange::<i32>::full().into_bounds(),
+            Some((Bound::Unbounded, Bound::Unbounded))
+        );
+    }
+
+    #[test]
+    pub fn bounds_tuple() {
+        assert_eq!(
+            <(Bound<_>, Bound<_>)>::try_from(ContinuousRange::to(5)),
+            Ok((Bound::Unbounded, Bound::Included(5)))
+        );
+        assert!(<(Bound<i32>, Bound<i32>)>::try_from(ContinuousRange::empty()).is_err());
+    }
+
+    #[test]
+    pub fn std_ranges() {
+        assert_eq!(
+            ops::Range::try_from(ContinuousRange::end_exclusive(1, 5)),
+            Ok(1..5)
+        );
+        assert_eq!(
+            ops::RangeInclusive::try_from(ContinuousRange::inclusive(1, 5)),
+            Ok(1..=5)
+        );
+        assert_eq!(
+            ops::RangeInclusive::try_from(ContinuousRange::single(3)),
+            Ok(3..=3)
+        );
+        assert_eq!(ops::RangeFrom::try_from(ContinuousRange::from(1)), Ok(1..));
+        assert_eq!(
+            ops::RangeTo::try_from(ContinuousRange::to_exclusive(5)),
+            Ok(..5)
+        );
+        assert_eq!(
+            ops::RangeToInclusive::try_from(ContinuousRange::to(5)),
+            Ok(..=5)
+        );
+        assert_eq!(
+            ops::RangeFull::try_from(ContinuousRange::<i32>::full()),
+            Ok(..)
+        );
+    }
+
+    #[test]
+    pub fn std_ranges_shape_mismatch() {
+        assert!(ops::Range::try_from(ContinuousRange::inclusive(1, 5)).is_err());
+        assert!(ops::Range::<i32>::try_from(ContinuousRange::empty()).is_err());
+        assert!(ops::RangeInclusive::try_from(ContinuousRange::start_exclusive(1, 5)).is_err());
+        assert!(ops::RangeFrom::try_from(ContinuousRange::from_exclusive(1)).is_err());
+        assert!(ops::RangeTo::try_from(ContinuousRange::to(5)).is_err());
+        assert!(ops::RangeFull::try_from(ContinuousRange::from(1)).is_err());
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let range = ContinuousRange::end_exclusive(1, 5);
+        let std_range = ops::Range::try_from(range.clone()).unwrap();
+        assert_eq!(ContinuousRange::<i32>::from_range_bounds(std_range), range);
+
+        let bounds = <(Bound<_>, Bound<_>)>::try_from(range.clone()).unwrap();
+        let from_bounds: ContinuousRange<_> = bounds.into();
+        assert_eq!(from_bounds, range);
+    }
+
+    #[test]
+    pub fn error_display() {
+        let error: TryFromRangeError = ops::Range::try_from(ContinuousRange::from(1)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the range can't be represented by the target type"
+        );
+    }
 }
 
 mod test_creation_functions {
@@ -1404,6 +1541,30 @@ mod test_union {
             Some(ContinuousRange::To(5))
         );
     }
+
+    #[test]
+    pub fn adjacent() {
+        union!(
+            ContinuousRange::EndExclusive(10, 20),
+            ContinuousRange::Inclusive(20, 30),
+            Some(ContinuousRange::Inclusive(10, 30))
+        );
+        union!(
+            ContinuousRange::Inclusive(20, 30),
+            ContinuousRange::EndExclusive(10, 20),
+            Some(ContinuousRange::Inclusive(10, 30))
+        );
+        union!(
+            ContinuousRange::To(20),
+            ContinuousRange::FromExclusive(20),
+            Some(ContinuousRange::Full)
+        );
+        union!(
+            ContinuousRange::EndExclusive(10, 20),
+            ContinuousRange::StartExclusive(20, 30),
+            None
+        );
+    }
 }
 
 mod test_start_end {
@@ -1692,3 +1853,732 @@ mod test_intersection {
         );
     }
 }
+
+mod test_difference {
+    use crate::ContinuousRange;
+
+    macro_rules! difference {
+        ($a:expr, $b:expr, $c:expr) => {
+            assert_eq!($a.difference(&$b), $c);
+        };
+    }
+
+    #[test]
+    pub fn empty() {
+        difference!(
+            ContinuousRange::Inclusive(10, 20),
+            ContinuousRange::Empty,
+            Some(ContinuousRange::Inclusive(10, 20))
+        );
+        difference!(
+            ContinuousRange::Empty,
+            ContinuousRange::Inclusive(10, 20),
+            Some(ContinuousRange::Empty)
+        );
+    }
+
+    #[test]
+    pub fn overlaps_and_is_overlapped() {
+        difference!(
+            ContinuousRange::Inclusive(10, 20),
+            ContinuousRange::Inclusive(15, 100),
+            Some(ContinuousRange::EndExclusive(10, 15))
+        );
+        difference!(
+            ContinuousRange::Inclusive(15, 100),
+            ContinuousRange::Inclusive(10, 20),
+            Some(ContinuousRange::StartExclusive(20, 100))
+        );
+    }
+
+    #[test]
+    pub fn strictly_contains() {
+        difference!(
+            ContinuousRange::Inclusive(0, 100),
+            ContinuousRange::Inclusive(5, 10),
+            None
+        );
+    }
+}
+
+mod test_symmetric_difference {
+    use crate::{ContinuousRange, Range};
+
+    macro_rules! symmetric_difference {
+        ($a:expr, $b:expr, $c:expr) => {
+            assert_eq!($a.symmetric_difference(&$b), $c);
+        };
+    }
+
+    #[test]
+    pub fn empty() {
+        symmetric_difference!(
+            ContinuousRange::Inclusive(10, 20),
+            ContinuousRange::Empty,
+            Range::Continuous(ContinuousRange::Inclusive(10, 20))
+        );
+        symmetric_difference!(
+            ContinuousRange::<i32>::Empty,
+            ContinuousRange::Empty,
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn equal() {
+        symmetric_difference!(
+            ContinuousRange::Inclusive(10, 20),
+            ContinuousRange::Inclusive(10, 20),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn overlaps() {
+        symmetric_difference!(
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(5, 15),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 5),
+                ContinuousRange::StartExclusive(10, 15)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn meets() {
+        symmetric_difference!(
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(10, 20),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 10),
+                ContinuousRange::StartExclusive(10, 20)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn disjoint() {
+        symmetric_difference!(
+            ContinuousRange::EndExclusive(0, 10),
+            ContinuousRange::Inclusive(10, 20),
+            Range::Continuous(ContinuousRange::Inclusive(0, 20))
+        );
+        symmetric_difference!(
+            ContinuousRange::Inclusive(0, 5),
+            ContinuousRange::Inclusive(10, 20),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(0, 5),
+                ContinuousRange::Inclusive(10, 20)
+            ])
+        );
+    }
+}
+
+mod test_operators {
+    use crate::{ContinuousRange, Range};
+
+    fn a() -> ContinuousRange<i32> {
+        ContinuousRange::Inclusive(0, 10)
+    }
+
+    fn b() -> ContinuousRange<i32> {
+        ContinuousRange::Inclusive(5, 15)
+    }
+
+    #[test]
+    pub fn bitor() {
+        let expected = Range::continuous(0, 15);
+        assert_eq!(a() | b(), expected);
+        assert_eq!(a() | &b(), expected);
+        assert_eq!(&a() | b(), expected);
+        assert_eq!(&a() | &b(), expected);
+        assert_eq!(
+            a() | ContinuousRange::Inclusive(20, 30),
+            Range::Composite(vec![a(), ContinuousRange::Inclusive(20, 30)])
+        );
+    }
+
+    #[test]
+    pub fn bitand() {
+        let expected = ContinuousRange::Inclusive(5, 10);
+        assert_eq!(a() & b(), expected);
+        assert_eq!(a() & &b(), expected);
+        assert_eq!(&a() & b(), expected);
+        assert_eq!(&a() & &b(), expected);
+    }
+
+    #[test]
+    pub fn sub() {
+        let expected = Range::continuous_end_exclusive(0, 5);
+        assert_eq!(a() - b(), expected);
+        assert_eq!(a() - &b(), expected);
+        assert_eq!(&a() - b(), expected);
+        assert_eq!(&a() - &b(), expected);
+        assert_eq!(
+            a() - ContinuousRange::Inclusive(3, 5),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 3),
+                ContinuousRange::StartExclusive(5, 10)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn bitxor() {
+        let expected = Range::Composite(vec![
+            ContinuousRange::EndExclusive(0, 5),
+            ContinuousRange::StartExclusive(10, 15),
+        ]);
+        assert_eq!(a() ^ b(), expected);
+        assert_eq!(a() ^ &b(), expected);
+        assert_eq!(&a() ^ b(), expected);
+        assert_eq!(&a() ^ &b(), expected);
+    }
+
+    #[test]
+    pub fn not() {
+        let expected = Range::Composite(vec![
+            ContinuousRange::ToExclusive(0),
+            ContinuousRange::FromExclusive(10),
+        ]);
+        assert_eq!(!a(), expected);
+        assert_eq!(!&a(), expected);
+    }
+
+    #[test]
+    pub fn bitand_assign() {
+        let mut r = a();
+        r &= b();
+        assert_eq!(r, ContinuousRange::Inclusive(5, 10));
+        r &= &ContinuousRange::Inclusive(8, 20);
+        assert_eq!(r, ContinuousRange::Inclusive(8, 10));
+    }
+}
+
+mod test_canonicalize {
+    use crate::ContinuousRange;
+
+    macro_rules! canonicalize {
+        ($a:expr, $b:expr) => {
+            assert_eq!($a.canonicalize(), $b);
+        };
+    }
+
+    #[test]
+    pub fn bounded() {
+        canonicalize!(
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::EndExclusive(1, 6)
+        );
+        canonicalize!(
+            ContinuousRange::EndExclusive(1, 6),
+            ContinuousRange::EndExclusive(1, 6)
+        );
+        canonicalize!(
+            ContinuousRange::StartExclusive(0, 5),
+            ContinuousRange::EndExclusive(1, 6)
+        );
+        canonicalize!(
+            ContinuousRange::Exclusive(0, 6),
+            ContinuousRange::EndExclusive(1, 6)
+        );
+        canonicalize!(
+            ContinuousRange::Single(5),
+            ContinuousRange::EndExclusive(5, 6)
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        canonicalize!(ContinuousRange::<i32>::Empty, ContinuousRange::Empty);
+        canonicalize!(ContinuousRange::Exclusive(1, 2), ContinuousRange::Empty);
+        canonicalize!(ContinuousRange::Inclusive(5, 1), ContinuousRange::Empty);
+        canonicalize!(
+            ContinuousRange::FromExclusive(u8::MAX),
+            ContinuousRange::Empty
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        canonicalize!(ContinuousRange::<i32>::Full, ContinuousRange::Full);
+        canonicalize!(ContinuousRange::From(5), ContinuousRange::From(5));
+        canonicalize!(ContinuousRange::FromExclusive(5), ContinuousRange::From(6));
+        canonicalize!(ContinuousRange::To(5), ContinuousRange::ToExclusive(6));
+        canonicalize!(
+            ContinuousRange::ToExclusive(5),
+            ContinuousRange::ToExclusive(5)
+        );
+    }
+
+    #[test]
+    pub fn max_value() {
+        canonicalize!(
+            ContinuousRange::Inclusive(250, u8::MAX),
+            ContinuousRange::Inclusive(250, u8::MAX)
+        );
+        canonicalize!(ContinuousRange::To(u8::MAX), ContinuousRange::To(u8::MAX));
+    }
+
+    #[test]
+    pub fn chars() {
+        canonicalize!(
+            ContinuousRange::Inclusive('a', 'z'),
+            ContinuousRange::EndExclusive('a', '{')
+        );
+    }
+
+    #[test]
+    pub fn union() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 3).union(&ContinuousRange::Inclusive(4, 6)),
+            None
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 3)
+                .canonicalize()
+                .union(&ContinuousRange::Inclusive(4, 6).canonicalize()),
+            Some(ContinuousRange::EndExclusive(1, 7))
+        );
+    }
+}
+
+mod test_discrete_union {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn union_keeps_bounds_as_written() {
+        assert_eq!(
+            ContinuousRange::inclusive(1, 3).union(&ContinuousRange::inclusive(4, 6)),
+            None
+        );
+    }
+
+    #[test]
+    pub fn merges_following_ranges() {
+        assert_eq!(
+            ContinuousRange::inclusive(1, 3).discrete_union(&ContinuousRange::inclusive(4, 6)),
+            Some(ContinuousRange::end_exclusive(1, 7))
+        );
+        assert_eq!(
+            ContinuousRange::exclusive(0, 4).discrete_union(&ContinuousRange::from(4)),
+            Some(ContinuousRange::from(1))
+        );
+        assert_eq!(
+            ContinuousRange::inclusive(1, 3).discrete_union(&ContinuousRange::inclusive(5, 6)),
+            None
+        );
+    }
+}
+
+mod test_discrete_eq {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn same_values() {
+        let ranges = [
+            ContinuousRange::inclusive(1, 5),
+            ContinuousRange::end_exclusive(1, 6),
+            ContinuousRange::start_exclusive(0, 5),
+            ContinuousRange::exclusive(0, 6),
+        ];
+        for a in &ranges {
+            for b in &ranges {
+                assert_eq!(a.discrete_eq(b), true, "{a:?} {b:?}");
+            }
+        }
+        assert_eq!(ranges[0] == ranges[1], false);
+    }
+
+    #[test]
+    pub fn different_values() {
+        assert_eq!(
+            ContinuousRange::inclusive(1, 5).discrete_eq(&ContinuousRange::inclusive(1, 6)),
+            false
+        );
+        assert_eq!(
+            ContinuousRange::<u8>::empty().discrete_eq(&ContinuousRange::single(0)),
+            false
+        );
+    }
+}
+
+mod test_iter {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<i32>::Empty.iter().next(), None);
+        assert_eq!(ContinuousRange::Inclusive(5, 1).iter().next(), None);
+        assert_eq!(ContinuousRange::Exclusive(1, 2).iter().next(), None);
+        assert_eq!(ContinuousRange::FromExclusive(u8::MAX).iter().next(), None);
+    }
+
+    #[test]
+    pub fn bounded() {
+        let values: Vec<_> = ContinuousRange::Single(5).iter().collect();
+        assert_eq!(values, vec![5]);
+        let values: Vec<_> = ContinuousRange::Inclusive(1, 5).iter().collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        let values: Vec<_> = ContinuousRange::Exclusive(1, 5).iter().collect();
+        assert_eq!(values, vec![2, 3, 4]);
+        let values: Vec<_> = ContinuousRange::StartExclusive(1, 5).iter().collect();
+        assert_eq!(values, vec![2, 3, 4, 5]);
+        let values: Vec<_> = ContinuousRange::EndExclusive(1, 5).iter().collect();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    pub fn max_value() {
+        let values: Vec<_> = ContinuousRange::From(253u8).iter().collect();
+        assert_eq!(values, vec![253, 254, 255]);
+        let values: Vec<_> = ContinuousRange::To(2u8).iter().rev().collect();
+        assert_eq!(values, vec![2, 1, 0]);
+    }
+
+    #[test]
+    pub fn chars() {
+        let values: String = ContinuousRange::Inclusive('a', 'e').iter().collect();
+        assert_eq!(values, "abcde");
+    }
+
+    #[test]
+    pub fn double_ended() {
+        let mut iter = ContinuousRange::Inclusive(1, 5).iter();
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    pub fn size_hint() {
+        let mut iter = ContinuousRange::EndExclusive(1, 5).iter();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(
+            ContinuousRange::<i32>::Empty.iter().size_hint(),
+            (0, Some(0))
+        );
+        assert_eq!(ContinuousRange::<i32>::Empty.iter().len(), 0);
+        assert_eq!(ContinuousRange::inclusive('a', 'z').iter().len(), 26);
+        assert_eq!(
+            ContinuousRange::inclusive(u16::MIN, u16::MAX).iter().len(),
+            65_536
+        );
+        assert_eq!(ContinuousRange::From(1).iter().size_hint(), (1, None));
+        assert_eq!(
+            ContinuousRange::inclusive(i64::MIN, i64::MAX)
+                .iter()
+                .size_hint(),
+            (usize::MAX, None)
+        );
+    }
+
+    #[test]
+    pub fn into_iterator() {
+        let mut values = vec![];
+        for value in &ContinuousRange::Inclusive(1, 3) {
+            values.push(value);
+        }
+        for value in ContinuousRange::Inclusive(10, 11) {
+            values.push(value);
+        }
+        assert_eq!(values, vec![1, 2, 3, 10, 11]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't iterate forward over a range without start bound")]
+    pub fn unbounded_start() {
+        let _ = ContinuousRange::To(5).iter().next();
+    }
+}
+
+mod test_count {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<i32>::Empty.count(), Some(0));
+        assert_eq!(ContinuousRange::Exclusive(1, 2).count(), Some(0));
+        assert_eq!(ContinuousRange::Inclusive(5, 1).count(), Some(0));
+    }
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(ContinuousRange::Single(5).count(), Some(1));
+        assert_eq!(ContinuousRange::Inclusive(1, 5).count(), Some(5));
+        assert_eq!(ContinuousRange::Exclusive(1, 5).count(), Some(3));
+        assert_eq!(ContinuousRange::StartExclusive(1, 5).count(), Some(4));
+        assert_eq!(ContinuousRange::EndExclusive(1, 5).count(), Some(4));
+        assert_eq!(ContinuousRange::Inclusive('a', 'z').count(), Some(26));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(ContinuousRange::<i32>::Full.count(), None);
+        assert_eq!(ContinuousRange::From(5).count(), None);
+        assert_eq!(ContinuousRange::ToExclusive(5).count(), None);
+    }
+
+    #[test]
+    pub fn large() {
+        assert_eq!(
+            ContinuousRange::Inclusive(i64::MIN, i64::MAX).count(),
+            Some(1u128 << 64)
+        );
+        assert_eq!(ContinuousRange::Inclusive(0, u128::MAX).count(), None);
+        assert_eq!(
+            ContinuousRange::EndExclusive(0, u128::MAX).count(),
+            Some(u128::MAX)
+        );
+    }
+}
+
+mod test_measure {
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<f64>::Empty.measure(), Some(0.0));
+        assert_eq!(ContinuousRange::Inclusive(5, 1).measure(), Some(0));
+    }
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(ContinuousRange::Single(1.5).measure(), Some(0.0));
+        assert_eq!(ContinuousRange::Inclusive(1.0, 2.5).measure(), Some(1.5));
+        assert_eq!(ContinuousRange::Exclusive(1.0, 2.5).measure(), Some(1.5));
+        assert_eq!(ContinuousRange::StartExclusive(1, 5).measure(), Some(4));
+        assert_eq!(ContinuousRange::EndExclusive(1, 5).measure(), Some(4));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(ContinuousRange::<f64>::Full.measure(), None);
+        assert_eq!(ContinuousRange::From(1.0).measure(), None);
+        assert_eq!(ContinuousRange::To(1.0).measure(), None);
+    }
+
+    #[test]
+    pub fn dates() {
+        let start: DateTime<Utc> = "2000-01-01T00:00:00Z".parse().unwrap();
+        let end: DateTime<Utc> = "2000-01-02T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            ContinuousRange::EndExclusive(start, end).measure(),
+            Some(Duration::hours(36))
+        );
+    }
+}
+
+mod test_try_as_range_bounds {
+    use crate::ContinuousRange;
+    use std::{
+        collections::BTreeMap,
+        ops::{Bound, RangeBounds},
+    };
+
+    #[test]
+    pub fn bounds() {
+        let range = ContinuousRange::start_exclusive(1, 5);
+        let bounds = range.try_as_range_bounds().unwrap();
+        assert_eq!(bounds.start_bound(), Bound::Excluded(&1));
+        assert_eq!(bounds.end_bound(), Bound::Included(&5));
+        assert_eq!(bounds.contains(&1), false);
+        assert_eq!(bounds.contains(&5), true);
+
+        let range = ContinuousRange::<i32>::full();
+        let bounds = range.try_as_range_bounds().unwrap();
+        assert_eq!(bounds.start_bound(), Bound::Unbounded);
+        assert_eq!(bounds.end_bound(), Bound::Unbounded);
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<i32>::empty().try_as_range_bounds(), None);
+        assert_eq!(ContinuousRange::Inclusive(5, 1).try_as_range_bounds(), None);
+        assert_eq!(ContinuousRange::Exclusive(3, 3).try_as_range_bounds(), None);
+    }
+
+    #[test]
+    pub fn btree_map() {
+        let map: BTreeMap<_, _> = (0..10).map(|i| (i, i * 10)).collect();
+        let values = |range: ContinuousRange<i32>| -> Vec<i32> {
+            map.range(range.try_as_range_bounds().unwrap())
+                .map(|(_, v)| *v)
+                .collect()
+        };
+
+        assert_eq!(values(ContinuousRange::single(3)), vec![30]);
+        assert_eq!(values(ContinuousRange::end_exclusive(7, 9)), vec![70, 80]);
+        assert_eq!(values(ContinuousRange::from_exclusive(7)), vec![80, 90]);
+        assert_eq!(values(ContinuousRange::to(1)), vec![0, 10]);
+    }
+
+    #[test]
+    pub fn vec_drain() {
+        let mut v = vec![0, 1, 2, 3, 4, 5];
+        let range = ContinuousRange::inclusive(1, 3);
+        let drained: Vec<_> = v.drain(range.try_as_range_bounds().unwrap()).collect();
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert_eq!(v, vec![0, 4, 5]);
+    }
+
+    #[test]
+    pub fn slicing() {
+        let v = [0, 1, 2, 3, 4, 5];
+        let range = ContinuousRange::exclusive(1, 4);
+        assert_eq!(&v[range.try_as_range_bounds().unwrap().cloned()], &[2, 3]);
+
+        let range = ContinuousRange::from(4usize);
+        assert_eq!(&"range"[range.try_as_range_bounds().unwrap().cloned()], "e");
+    }
+}
+
+mod test_gaps {
+    use crate::ContinuousRange;
+
+    fn gaps(
+        within: &ContinuousRange<i32>,
+        ranges: &[ContinuousRange<i32>],
+    ) -> Vec<ContinuousRange<i32>> {
+        within.gaps(ranges).collect()
+    }
+
+    #[test]
+    pub fn disjoint() {
+        assert_eq!(
+            gaps(
+                &ContinuousRange::inclusive(0, 10),
+                &[
+                    ContinuousRange::end_exclusive(1, 3),
+                    ContinuousRange::inclusive(5, 7),
+                ]
+            ),
+            vec![
+                ContinuousRange::end_exclusive(0, 1),
+                ContinuousRange::end_exclusive(3, 5),
+                ContinuousRange::start_exclusive(7, 10),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn overlapping() {
+        assert_eq!(
+            gaps(
+                &ContinuousRange::end_exclusive(0, 20),
+                &[
+                    ContinuousRange::end_exclusive(2, 10),
+                    ContinuousRange::end_exclusive(3, 5),
+                    ContinuousRange::end_exclusive(4, 12),
+                    ContinuousRange::inclusive(12, 15),
+                ]
+            ),
+            vec![
+                ContinuousRange::end_exclusive(0, 2),
+                ContinuousRange::exclusive(15, 20),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn outside() {
+        assert_eq!(
+            gaps(
+                &ContinuousRange::inclusive(5, 10),
+                &[
+                    ContinuousRange::to(2),
+                    ContinuousRange::end_exclusive(3, 5),
+                    ContinuousRange::inclusive(7, 7),
+                    ContinuousRange::start_exclusive(10, 12),
+                    ContinuousRange::from(20),
+                ]
+            ),
+            vec![
+                ContinuousRange::end_exclusive(5, 7),
+                ContinuousRange::start_exclusive(7, 10),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn fully_covered() {
+        assert_eq!(
+            gaps(
+                &ContinuousRange::inclusive(5, 10),
+                &[ContinuousRange::to(7), ContinuousRange::from(6)]
+            ),
+            vec![]
+        );
+        assert_eq!(
+            gaps(
+                &ContinuousRange::inclusive(5, 10),
+                &[ContinuousRange::full()]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            gaps(
+                &ContinuousRange::full(),
+                &[
+                    ContinuousRange::end_exclusive(1, 3),
+                    ContinuousRange::inclusive(5, 7),
+                ]
+            ),
+            vec![
+                ContinuousRange::to_exclusive(1),
+                ContinuousRange::end_exclusive(3, 5),
+                ContinuousRange::from_exclusive(7),
+            ]
+        );
+        assert_eq!(
+            gaps(&ContinuousRange::from(0), &[ContinuousRange::to(5)]),
+            vec![ContinuousRange::from_exclusive(5)]
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(
+            gaps(&ContinuousRange::to(5), &[]),
+            vec![ContinuousRange::to(5)]
+        );
+        assert_eq!(
+            gaps(
+                &ContinuousRange::inclusive(0, 5),
+                &[ContinuousRange::empty(), ContinuousRange::exclusive(3, 3)]
+            ),
+            vec![ContinuousRange::inclusive(0, 5)]
+        );
+        assert_eq!(
+            gaps(&ContinuousRange::empty(), &[ContinuousRange::single(1)]),
+            vec![]
+        );
+        assert_eq!(gaps(&ContinuousRange::inclusive(5, 1), &[]), vec![]);
+    }
+
+    #[test]
+    pub fn fused() {
+        let ranges = [ContinuousRange::single(5)];
+        let within = ContinuousRange::inclusive(0, 10);
+        let mut iter = within.gaps(&ranges);
+        assert_eq!(iter.next(), Some(ContinuousRange::end_exclusive(0, 5)));
+        assert_eq!(iter.next(), Some(ContinuousRange::start_exclusive(5, 10)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,61 @@
+//! Conversions from ranges to the std range types and to tuples of [`Bound`].
+//!
+//! The conversions from std types are implemented next to each range type as they can't fail.
+
+use std::{convert::TryFrom, error::Error, fmt, ops, ops::Bound};
+
+use crate::{ContinuousRange, Range};
+
+/// The error returned when a range can't be converted to a type that can't represent its shape, like an empty range
+/// to a [`std::ops::Range`] or a range with an exclusive start to any std range type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TryFromRangeError(());
+
+impl fmt::Display for TryFromRangeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "the range can't be represented by the target type")
+    }
+}
+
+impl Error for TryFromRangeError {}
+
+/// The single component of a range, composite ranges with zero or multiple components can't be converted
+fn single_component<Idx>(range: Range<Idx>) -> Result<ContinuousRange<Idx>, TryFromRangeError> {
+    match range {
+        Range::Continuous(r) => Ok(r),
+        Range::Composite(mut v) if v.len() == 1 => Ok(v.remove(0)),
+        Range::Composite(_) => Err(TryFromRangeError(())),
+    }
+}
+
+/// Implement `TryFrom` for both range types to a type built from the bounds of the range
+macro_rules! try_from_bounds {
+    ($target:ty, $bounds:pat => $result:expr) => {
+        impl<Idx: PartialOrd + Clone> TryFrom<ContinuousRange<Idx>> for $target {
+            type Error = TryFromRangeError;
+
+            fn try_from(range: ContinuousRange<Idx>) -> Result<Self, Self::Error> {
+                match range.into_bounds() {
+                    Some($bounds) => Ok($result),
+                    _ => Err(TryFromRangeError(())),
+                }
+            }
+        }
+
+        impl<Idx: PartialOrd + Clone> TryFrom<Range<Idx>> for $target {
+            type Error = TryFromRangeError;
+
+            fn try_from(range: Range<Idx>) -> Result<Self, Self::Error> {
+                Self::try_from(single_component(range)?)
+            }
+        }
+    };
+}
+
+try_from_bounds!((Bound<Idx>, Bound<Idx>), bounds => bounds);
+try_from_bounds!(ops::RangeFull, (Bound::Unbounded, Bound::Unbounded) => ..);
+try_from_bounds!(ops::Range<Idx>, (Bound::Included(start), Bound::Excluded(end)) => start..end);
+try_from_bounds!(ops::RangeInclusive<Idx>, (Bound::Included(start), Bound::Included(end)) => start..=end);
+try_from_bounds!(ops::RangeFrom<Idx>, (Bound::Included(start), Bound::Unbounded) => start..);
+try_from_bounds!(ops::RangeTo<Idx>, (Bound::Unbounded, Bound::Excluded(end)) => ..end);
+try_from_bounds!(ops::RangeToInclusive<Idx>, (Bound::Unbounded, Bound::Included(end)) => ..=end);
@@ -0,0 +1,177 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    iter::{FromIterator, Sum},
+    ops,
+    ops::Bound,
+};
+
+use crate::{
+    bounds::{are_adjacent, partial_cmp_bounds, reverse_bound, BoundSide},
+    entries::{range_from_disjoint, stored_bounds},
+    ContinuousRange, Range,
+};
+
+/// How many ranges of a collection cover each part of the values, as sorted segments of constant depth.
+///
+/// The segments are disjoint and only cover the values contained in at least one range, two consecutive segments
+/// always have a different depth or a gap between them.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, Coverage};
+///
+/// let reservations = vec![
+///     ContinuousRange::end_exclusive(9, 12),
+///     ContinuousRange::end_exclusive(10, 14),
+///     ContinuousRange::end_exclusive(11, 13),
+/// ];
+/// let coverage: Coverage<_> = reservations.into_iter().collect();
+///
+/// assert_eq!(coverage.max_depth(), 3);
+/// assert_eq!(
+///     coverage.segments(),
+///     &[
+///         (ContinuousRange::end_exclusive(9, 10), 1),
+///         (ContinuousRange::end_exclusive(10, 11), 2),
+///         (ContinuousRange::end_exclusive(11, 12), 3),
+///         (ContinuousRange::end_exclusive(12, 13), 2),
+///         (ContinuousRange::end_exclusive(13, 14), 1),
+///     ]
+/// );
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Coverage<Idx> {
+    segments: Vec<(ContinuousRange<Idx>, usize)>,
+}
+
+/// Order the positions of the sweep line, each being the start bound of the segment following it
+pub(crate) fn cmp_positions<Idx: PartialOrd>(a: &Bound<Idx>, b: &Bound<Idx>) -> Ordering {
+    partial_cmp_bounds(&a.as_ref(), BoundSide::Start, &b.as_ref(), BoundSide::Start)
+        .expect("Range bounds must be comparable")
+}
+
+impl<Idx: PartialOrd + Clone> Coverage<Idx> {
+    /// Compute the coverage of `ranges`, empty ranges don't cover anything
+    ///
+    /// # Panics
+    ///
+    /// Panics if some bounds can't be compared, like a `NaN` float.
+    pub fn new(ranges: impl IntoIterator<Item = ContinuousRange<Idx>>) -> Self {
+        // Positions where the depth can change, as the start bound of the segment following them, with the number of
+        // ranges starting and ending there. A range ends at the position of the start bound directly following it:
+        // `[1..5]` ends at `(5`.
+        let mut cuts: Vec<(Bound<Idx>, usize, usize)> = Vec::new();
+        for range in ranges {
+            let Some((start, end)) = range.range_bounds().filter(|_| !range.is_empty()) else {
+                continue;
+            };
+
+            cuts.push((start.cloned(), 1, 0));
+            if end != Bound::Unbounded {
+                cuts.push((reverse_bound(end).cloned(), 0, 1));
+            }
+        }
+        cuts.sort_by(|(a, _, _), (b, _, _)| cmp_positions(a, b));
+
+        let mut segments = Vec::new();
+        let mut depth = 0;
+        let mut segment_start: Option<Bound<Idx>> = None;
+        let mut cuts = cuts.into_iter().peekable();
+        while let Some((position, mut opened, mut closed)) = cuts.next() {
+            while let Some((_, next_opened, next_closed)) =
+                cuts.next_if(|(next, _, _)| cmp_positions(&position, next) == Ordering::Equal)
+            {
+                opened += next_opened;
+                closed += next_closed;
+            }
+            if opened == closed {
+                continue;
+            }
+
+            if let Some(start) = segment_start.take() {
+                let end = reverse_bound(position.as_ref());
+                segments.push((ContinuousRange::from_bounds((start.as_ref(), end)), depth));
+            }
+            depth = depth + opened - closed;
+            if depth > 0 {
+                segment_start = Some(position);
+            }
+        }
+        if let Some(start) = segment_start {
+            segments.push((
+                ContinuousRange::from_bounds((start.as_ref(), Bound::Unbounded)),
+                depth,
+            ));
+        }
+
+        Self { segments }
+    }
+
+    /// The segments of constant depth, in order
+    #[must_use]
+    pub fn segments(&self) -> &[(ContinuousRange<Idx>, usize)] {
+        &self.segments
+    }
+
+    /// Take the segments of constant depth, in order
+    #[must_use]
+    pub fn into_segments(self) -> Vec<(ContinuousRange<Idx>, usize)> {
+        self.segments
+    }
+
+    /// The greatest number of ranges covering the same value, zero if no value is covered
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|(_, depth)| *depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The values covered by at least `depth` ranges
+    #[must_use]
+    pub fn at_least(&self, depth: usize) -> Range<Idx> {
+        let mut ranges: Vec<ContinuousRange<Idx>> = Vec::new();
+        for (segment, _) in self.segments.iter().filter(|(_, d)| *d >= depth) {
+            let (start, end) = stored_bounds(segment);
+            if let Some(last) = ranges.last_mut() {
+                let (last_start, last_end) = stored_bounds(last);
+                if are_adjacent(&last_end, &start) {
+                    *last = ContinuousRange::from_bounds((last_start, end));
+                    continue;
+                }
+            }
+            ranges.push(segment.clone());
+        }
+
+        range_from_disjoint(ranges)
+    }
+
+    /// The total [measure][ContinuousRange::measure] of the segments of each depth
+    ///
+    /// Depths without segments are absent and a depth including an unbounded segment has no measure.
+    #[must_use]
+    pub fn measure_by_depth<M>(&self) -> BTreeMap<usize, Option<M>>
+    where
+        Idx: ops::Sub<Output = M>,
+        M: Default + Sum,
+    {
+        let mut by_depth: BTreeMap<usize, Vec<Option<M>>> = BTreeMap::new();
+        for (segment, depth) in &self.segments {
+            by_depth.entry(*depth).or_default().push(segment.measure());
+        }
+
+        by_depth
+            .into_iter()
+            .map(|(depth, measures)| (depth, measures.into_iter().sum()))
+            .collect()
+    }
+}
+
+impl<Idx: PartialOrd + Clone> FromIterator<ContinuousRange<Idx>> for Coverage<Idx> {
+    fn from_iter<T: IntoIterator<Item = ContinuousRange<Idx>>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
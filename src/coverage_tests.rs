@@ -0,0 +1,182 @@
+mod test_segments {
+    use crate::{ContinuousRange, Coverage};
+
+    #[test]
+    pub fn empty() {
+        let coverage = Coverage::<i32>::new(vec![]);
+        assert_eq!(coverage.segments(), &[]);
+        assert_eq!(coverage.max_depth(), 0);
+
+        let coverage = Coverage::new(vec![
+            ContinuousRange::empty(),
+            ContinuousRange::exclusive(1, 1),
+        ]);
+        assert_eq!(coverage.segments(), &[]);
+    }
+
+    #[test]
+    pub fn disjoint() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::inclusive(5, 8),
+            ContinuousRange::end_exclusive(1, 3),
+        ]);
+        assert_eq!(
+            coverage.into_segments(),
+            vec![
+                (ContinuousRange::end_exclusive(1, 3), 1),
+                (ContinuousRange::inclusive(5, 8), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn nested() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::inclusive(1, 10),
+            ContinuousRange::exclusive(3, 5),
+        ]);
+        assert_eq!(
+            coverage.into_segments(),
+            vec![
+                (ContinuousRange::inclusive(1, 3), 1),
+                (ContinuousRange::exclusive(3, 5), 2),
+                (ContinuousRange::inclusive(5, 10), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn shared_inclusive_bound() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::inclusive(1, 5),
+            ContinuousRange::inclusive(5, 8),
+        ]);
+        assert_eq!(
+            coverage.into_segments(),
+            vec![
+                (ContinuousRange::end_exclusive(1, 5), 1),
+                (ContinuousRange::single(5), 2),
+                (ContinuousRange::start_exclusive(5, 8), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn adjacent_are_merged() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::end_exclusive(1, 5),
+            ContinuousRange::inclusive(5, 8),
+            ContinuousRange::start_exclusive(8, 10),
+        ]);
+        assert_eq!(
+            coverage.into_segments(),
+            vec![(ContinuousRange::inclusive(1, 10), 1)]
+        );
+    }
+
+    #[test]
+    pub fn exclusive_bounds_leave_gap() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::end_exclusive(1, 5),
+            ContinuousRange::start_exclusive(5, 8),
+        ]);
+        assert_eq!(
+            coverage.into_segments(),
+            vec![
+                (ContinuousRange::end_exclusive(1, 5), 1),
+                (ContinuousRange::start_exclusive(5, 8), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn same_range() {
+        let coverage = Coverage::new(vec![ContinuousRange::single(3); 4]);
+        assert_eq!(
+            coverage.into_segments(),
+            vec![(ContinuousRange::single(3), 4)]
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let coverage: Coverage<_> = vec![
+            ContinuousRange::full(),
+            ContinuousRange::to(5),
+            ContinuousRange::from_exclusive(3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            coverage.into_segments(),
+            vec![
+                (ContinuousRange::to(3), 2),
+                (ContinuousRange::start_exclusive(3, 5), 3),
+                (ContinuousRange::from_exclusive(5), 2),
+            ]
+        );
+    }
+}
+
+mod test_at_least {
+    use crate::{ContinuousRange, Coverage, Range};
+
+    fn coverage() -> Coverage<i32> {
+        Coverage::new(vec![
+            ContinuousRange::end_exclusive(0, 10),
+            ContinuousRange::end_exclusive(2, 6),
+            ContinuousRange::end_exclusive(4, 8),
+            ContinuousRange::end_exclusive(20, 30),
+        ])
+    }
+
+    #[test]
+    pub fn depths() {
+        assert_eq!(
+            coverage().at_least(1),
+            Range::Composite(vec![
+                ContinuousRange::end_exclusive(0, 10),
+                ContinuousRange::end_exclusive(20, 30),
+            ])
+        );
+        assert_eq!(
+            coverage().at_least(2),
+            Range::continuous_end_exclusive(2, 8)
+        );
+        assert_eq!(
+            coverage().at_least(3),
+            Range::continuous_end_exclusive(4, 6)
+        );
+        assert_eq!(coverage().at_least(4), Range::empty());
+    }
+
+    #[test]
+    pub fn zero_is_covered_values() {
+        assert_eq!(coverage().at_least(0), coverage().at_least(1));
+    }
+}
+
+mod test_measure_by_depth {
+    use crate::{ContinuousRange, Coverage};
+    use std::collections::BTreeMap;
+
+    #[test]
+    pub fn bounded() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::end_exclusive(0.0, 10.0),
+            ContinuousRange::end_exclusive(2.5, 5.0),
+        ]);
+        let expected: BTreeMap<_, _> = vec![(1, Some(7.5)), (2, Some(2.5))].into_iter().collect();
+        assert_eq!(coverage.measure_by_depth(), expected);
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let coverage = Coverage::new(vec![
+            ContinuousRange::from(0),
+            ContinuousRange::inclusive(5, 10),
+        ]);
+        let expected: BTreeMap<_, _> = vec![(1, None), (2, Some(5))].into_iter().collect();
+        assert_eq!(coverage.measure_by_depth(), expected);
+    }
+}
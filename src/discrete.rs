@@ -0,0 +1,118 @@
+use std::ops::Bound;
+
+use crate::ContinuousRange;
+
+/// A type where each value has a well defined next and previous value, like integers.
+///
+/// Ranges over discrete types have multiple representations of the same set of values: `[1..5]`, `[1..6)`, `(0..5]`
+/// and `(0..6)` are all equivalent. They can be converted to a single canonical representation using
+/// [`ContinuousRange::canonicalize`][crate::ContinuousRange::canonicalize] or
+/// [`Range::canonicalize`][crate::Range::canonicalize].
+pub trait Discrete: Sized {
+    /// The value directly after this one or [None] if it's the largest value of the type
+    fn successor(&self) -> Option<Self>;
+
+    /// The value directly before this one or [None] if it's the smallest value of the type
+    fn predecessor(&self) -> Option<Self>;
+
+    /// The number of [successor][Discrete::successor] steps needed to go from `start` to `end` or [None] if `end`
+    /// is before `start`
+    fn steps_between(start: &Self, end: &Self) -> Option<u128>;
+}
+
+macro_rules! impl_discrete_integer {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn steps_between(start: &Self, end: &Self) -> Option<u128> {
+                    if end < start {
+                        None
+                    } else {
+                        // The difference always fits in the unsigned type of the same size
+                        Some(end.wrapping_sub(*start) as $unsigned as u128)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete_integer!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize
+);
+
+/// Characters skip the surrogate code points `U+D800` to `U+DFFF` that aren't valid [`char`] values.
+impl Discrete for char {
+    fn successor(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            c => char::from_u32(c as u32 + 1),
+        }
+    }
+
+    fn predecessor(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            c => (c as u32).checked_sub(1).and_then(char::from_u32),
+        }
+    }
+
+    fn steps_between(start: &Self, end: &Self) -> Option<u128> {
+        const SURROGATES: u32 = 0xE000 - 0xD800;
+
+        let (start, end) = (*start as u32, *end as u32);
+        let steps = end.checked_sub(start)?;
+        if start < 0xD800 && end >= 0xE000 {
+            Some(u128::from(steps - SURROGATES))
+        } else {
+            Some(u128::from(steps))
+        }
+    }
+}
+
+/// Get the first and last values contained in a range of [`Discrete`] values as [`Bound::Included`] or
+/// [`Bound::Unbounded`] if the range is unbounded on that side.
+///
+/// Returns [None] if the range doesn't contain any value.
+pub fn inclusive_bounds<Idx: Discrete + PartialOrd + Clone>(
+    range: &ContinuousRange<Idx>,
+) -> Option<(Bound<Idx>, Bound<Idx>)> {
+    let (start, end) = range.range_bounds()?;
+
+    let start = match start {
+        Bound::Included(start) => Bound::Included(start.clone()),
+        Bound::Excluded(start) => Bound::Included(start.successor()?),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+
+    let end = match end {
+        Bound::Included(end) => Bound::Included(end.clone()),
+        Bound::Excluded(end) => Bound::Included(end.predecessor()?),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+
+    match (&start, &end) {
+        (Bound::Included(start), Bound::Included(end)) if start > end => None,
+        _ => Some((start, end)),
+    }
+}
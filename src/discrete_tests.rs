@@ -0,0 +1,58 @@
+mod test_integers {
+    use crate::Discrete;
+
+    #[test]
+    pub fn successor() {
+        assert_eq!(5i32.successor(), Some(6));
+        assert_eq!((-1i64).successor(), Some(0));
+        assert_eq!(254u8.successor(), Some(255));
+        assert_eq!(255u8.successor(), None);
+    }
+
+    #[test]
+    pub fn predecessor() {
+        assert_eq!(5i32.predecessor(), Some(4));
+        assert_eq!(0i64.predecessor(), Some(-1));
+        assert_eq!(1u8.predecessor(), Some(0));
+        assert_eq!(0u8.predecessor(), None);
+        assert_eq!((-128i8).predecessor(), None);
+    }
+
+    #[test]
+    pub fn steps_between() {
+        assert_eq!(i32::steps_between(&1, &5), Some(4));
+        assert_eq!(i32::steps_between(&5, &5), Some(0));
+        assert_eq!(i32::steps_between(&5, &1), None);
+        assert_eq!(i8::steps_between(&i8::MIN, &i8::MAX), Some(255));
+        assert_eq!(
+            u64::steps_between(&0, &u64::MAX),
+            Some(u128::from(u64::MAX))
+        );
+        assert_eq!(i128::steps_between(&i128::MIN, &i128::MAX), Some(u128::MAX));
+    }
+}
+
+mod test_char {
+    use crate::Discrete;
+
+    #[test]
+    pub fn successor() {
+        assert_eq!('a'.successor(), Some('b'));
+        assert_eq!('\u{D7FF}'.successor(), Some('\u{E000}'));
+        assert_eq!(char::MAX.successor(), None);
+    }
+
+    #[test]
+    pub fn predecessor() {
+        assert_eq!('b'.predecessor(), Some('a'));
+        assert_eq!('\u{E000}'.predecessor(), Some('\u{D7FF}'));
+        assert_eq!('\0'.predecessor(), None);
+    }
+
+    #[test]
+    pub fn steps_between() {
+        assert_eq!(char::steps_between(&'a', &'z'), Some(25));
+        assert_eq!(char::steps_between(&'z', &'a'), None);
+        assert_eq!(char::steps_between(&'\u{D7FF}', &'\u{E000}'), Some(1));
+    }
+}
@@ -0,0 +1,92 @@
+//! Helpers shared by the collections storing disjoint ranges in a [`BTreeMap`] keyed by their start bound.
+
+use std::{collections::BTreeMap, ops::Bound};
+
+use crate::{
+    bounds::{end_overlaps_start, StartBound},
+    ContinuousRange, Range,
+};
+
+/// The bounds of a range stored in one of the collections, that are never empty
+pub(crate) fn stored_bounds<Idx: PartialOrd + Clone>(
+    range: &ContinuousRange<Idx>,
+) -> (Bound<&Idx>, Bound<&Idx>) {
+    range.range_bounds().expect("Stored ranges are never empty")
+}
+
+/// An entry of the maps backing [`crate::RangeSet`] and [`crate::RangeMap`], keyed by the start bound of its range
+pub(crate) trait RangeEntry<Idx> {
+    fn range(&self) -> &ContinuousRange<Idx>;
+}
+
+impl<Idx> RangeEntry<Idx> for ContinuousRange<Idx> {
+    fn range(&self) -> &ContinuousRange<Idx> {
+        self
+    }
+}
+
+impl<Idx, V> RangeEntry<Idx> for (ContinuousRange<Idx>, V) {
+    fn range(&self) -> &ContinuousRange<Idx> {
+        &self.0
+    }
+}
+
+/// The entry whose range contains `value`, if any
+pub(crate) fn entry_containing<'a, Idx, E>(
+    entries: &'a BTreeMap<StartBound<Idx>, E>,
+    value: &Idx,
+) -> Option<&'a E>
+where
+    Idx: Ord + Clone,
+    E: RangeEntry<Idx>,
+{
+    entries
+        .range(..=StartBound(Bound::Included(value.clone())))
+        .next_back()
+        .map(|(_, entry)| entry)
+        .filter(|entry| entry.range().contains(value))
+}
+
+/// The entries whose ranges share at least one value with `range`, in order
+///
+/// Only the entry starting before `range` can overlap it without starting inside it, as entries are disjoint.
+pub(crate) fn overlapping_entries<'a, Idx, E>(
+    entries: &'a BTreeMap<StartBound<Idx>, E>,
+    range: &ContinuousRange<Idx>,
+) -> impl Iterator<Item = &'a E> + 'a
+where
+    Idx: Ord + Clone,
+    E: RangeEntry<Idx>,
+{
+    let (previous, after, end) = match range.range_bounds().filter(|_| !range.is_empty()) {
+        Some((start, end)) => {
+            let previous = entries
+                .range(..=StartBound(start.cloned()))
+                .next_back()
+                .map(|(_, previous)| previous)
+                .filter(|previous| end_overlaps_start(stored_bounds(previous.range()).1, start));
+            let after = (
+                Bound::Excluded(StartBound(start.cloned())),
+                Bound::Unbounded,
+            );
+            (previous, entries.range(after), Some(end.cloned()))
+        }
+        None => (None, entries.range(..), None),
+    };
+
+    previous
+        .into_iter()
+        .chain(after.map(|(_, next)| next).take_while(move |next| {
+            end.as_ref()
+                .is_some_and(|end| end_overlaps_start(end.as_ref(), stored_bounds(next.range()).0))
+        }))
+}
+
+/// Build a range from sorted, disjoint and non-adjacent ranges, that is already simplified
+pub(crate) fn range_from_disjoint<Idx>(mut ranges: Vec<ContinuousRange<Idx>>) -> Range<Idx> {
+    match ranges.len() {
+        0 => Range::Continuous(ContinuousRange::Empty),
+        1 => Range::Continuous(ranges.remove(0)),
+        _ => Range::Composite(ranges),
+    }
+}
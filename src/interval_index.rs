@@ -0,0 +1,278 @@
+use std::{
+    borrow::Borrow,
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::Bound,
+    slice,
+};
+
+use crate::{
+    bounds::{end_overlaps_start, max_end},
+    entries::stored_bounds,
+    interval_tree::cmp_ranges,
+    ContinuousRange,
+};
+
+/// An immutable collection of possibly overlapping [`ContinuousRange`]s associated with values, built once and
+/// answering stabbing and overlap queries in O(log n + k) for k results.
+///
+/// Entries are stored in a single array sorted by start bound then end bound. The array is read as an implicit
+/// balanced tree, the middle entry of each slice being the root of the subtree made of that slice, and a second array
+/// holds the greatest end bound of each subtree. Queries only read these two arrays and the index has no interior
+/// mutability, so it can be shared between threads when the ranges and values can.
+///
+/// Use [`crate::IntervalTree`] instead when entries need to be added or removed after construction.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, IntervalIndex};
+///
+/// let genes: IntervalIndex<u64, &str> = vec![
+///     (ContinuousRange::inclusive(100, 250), "abc1"),
+///     (ContinuousRange::inclusive(200, 400), "xyz2"),
+///     (ContinuousRange::inclusive(600, 700), "def3"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let names: Vec<_> = genes.containing(220).map(|(_, name)| *name).collect();
+/// assert_eq!(names, vec!["abc1", "xyz2"]);
+/// ```
+#[derive(Clone)]
+pub struct IntervalIndex<Idx, V> {
+    /// Entries with non-empty ranges, sorted by start bound then end bound
+    entries: Vec<(ContinuousRange<Idx>, V)>,
+    /// For each entry, the greatest end bound of the implicit subtree it is the root of
+    max_ends: Vec<Bound<Idx>>,
+}
+
+/// Middle of a slice of entries, the root of its implicit subtree
+fn middle(start: usize, end: usize) -> usize {
+    start + (end - start) / 2
+}
+
+/// Compute the greatest end bound of the implicit subtree of `entries[start..end]` and its children, returning the
+/// position of its root
+fn fill_max_ends<Idx: Ord + Clone, V>(
+    entries: &[(ContinuousRange<Idx>, V)],
+    max_ends: &mut [Bound<Idx>],
+    start: usize,
+    end: usize,
+) -> Option<usize> {
+    if start >= end {
+        return None;
+    }
+
+    let root = middle(start, end);
+    let left = fill_max_ends(entries, max_ends, start, root);
+    let right = fill_max_ends(entries, max_ends, root + 1, end);
+
+    let mut subtree_end = stored_bounds(&entries[root].0).1;
+    for child in left.into_iter().chain(right) {
+        subtree_end = max_end(subtree_end, max_ends[child].as_ref());
+    }
+    max_ends[root] = subtree_end.cloned();
+    Some(root)
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> IntervalIndex<Idx, V> {
+    /// Number of entries in the index
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the index contains no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over all the entries, ordered by start bound then end bound
+    #[must_use]
+    pub fn iter(&self) -> IntervalIndexEntries<'_, Idx, V> {
+        IntervalIndexEntries {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Iterate over the entries whose range intersects `range`, ordered by start bound then end bound
+    ///
+    /// Exclusive bounds are respected like in [`ContinuousRange::intersects`]: `[1..5)` doesn't intersect `[5..8]`.
+    #[must_use]
+    pub fn overlapping(&self, range: &ContinuousRange<Idx>) -> IntervalIndexIter<'_, Idx, V> {
+        IntervalIndexIter::new(self, range.clone())
+    }
+
+    /// Iterate over the entries whose range contains `value`, ordered by start bound then end bound
+    #[must_use]
+    pub fn containing(&self, value: impl Borrow<Idx>) -> IntervalIndexIter<'_, Idx, V> {
+        self.overlapping(&ContinuousRange::Single(value.borrow().clone()))
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> FromIterator<(ContinuousRange<Idx>, V)>
+    for IntervalIndex<Idx, V>
+{
+    /// Build the index, entries with an empty range are ignored as they can't be returned by any query
+    fn from_iter<T: IntoIterator<Item = (ContinuousRange<Idx>, V)>>(iter: T) -> Self {
+        let mut entries: Vec<_> = iter
+            .into_iter()
+            .filter(|(range, _)| !range.is_empty())
+            .collect();
+        entries.sort_by(|(a, _), (b, _)| cmp_ranges(a, b));
+
+        let mut max_ends = vec![Bound::Unbounded; entries.len()];
+        fill_max_ends(&entries, &mut max_ends, 0, entries.len());
+
+        Self { entries, max_ends }
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> Default for IntervalIndex<Idx, V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            max_ends: Vec::new(),
+        }
+    }
+}
+
+impl<Idx: fmt::Debug, V: fmt::Debug> fmt::Debug for IntervalIndex<Idx, V> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map()
+            .entries(self.entries.iter().map(|(range, value)| (range, value)))
+            .finish()
+    }
+}
+
+impl<'a, Idx: Ord + Clone + fmt::Debug, V> IntoIterator for &'a IntervalIndex<Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+    type IntoIter = IntervalIndexEntries<'a, Idx, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over all the entries of an [`IntervalIndex`], ordered by start bound then end bound.
+///
+/// Created by [`IntervalIndex::iter`].
+#[derive(Clone, Debug)]
+pub struct IntervalIndexEntries<'a, Idx, V> {
+    inner: slice::Iter<'a, (ContinuousRange<Idx>, V)>,
+}
+
+impl<'a, Idx, V> Iterator for IntervalIndexEntries<'a, Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(range, value)| (range, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<Idx, V> DoubleEndedIterator for IntervalIndexEntries<'_, Idx, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(range, value)| (range, value))
+    }
+}
+
+impl<Idx, V> ExactSizeIterator for IntervalIndexEntries<'_, Idx, V> {}
+
+impl<Idx, V> FusedIterator for IntervalIndexEntries<'_, Idx, V> {}
+
+/// A step of the in-order traversal of the implicit tree
+#[derive(Clone, Copy, Debug)]
+enum Visit {
+    /// The subtree made of the entries between the two positions
+    Subtree(usize, usize),
+    /// A single entry, whose left subtree was already visited
+    Entry(usize),
+}
+
+/// Iterator over the entries of an [`IntervalIndex`] intersecting a range.
+///
+/// Created by [`IntervalIndex::overlapping`] and [`IntervalIndex::containing`].
+#[derive(Clone)]
+pub struct IntervalIndexIter<'a, Idx, V> {
+    entries: &'a [(ContinuousRange<Idx>, V)],
+    max_ends: &'a [Bound<Idx>],
+    /// Remaining steps, the top being the next in order
+    stack: Vec<Visit>,
+    query: ContinuousRange<Idx>,
+    query_start: Bound<Idx>,
+    query_end: Bound<Idx>,
+}
+
+impl<'a, Idx: Ord + Clone + fmt::Debug, V> IntervalIndexIter<'a, Idx, V> {
+    fn new(index: &'a IntervalIndex<Idx, V>, query: ContinuousRange<Idx>) -> Self {
+        let bounds = query
+            .range_bounds()
+            .filter(|_| !query.is_empty())
+            .map(|(start, end)| (start.cloned(), end.cloned()));
+        let stack = match bounds {
+            Some(_) => vec![Visit::Subtree(0, index.entries.len())],
+            None => Vec::new(),
+        };
+        let (query_start, query_end) = bounds.unwrap_or((Bound::Unbounded, Bound::Unbounded));
+
+        Self {
+            entries: &index.entries,
+            max_ends: &index.max_ends,
+            stack,
+            query,
+            query_start,
+            query_end,
+        }
+    }
+}
+
+impl<'a, Idx: Ord + Clone + fmt::Debug, V> Iterator for IntervalIndexIter<'a, Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(visit) = self.stack.pop() {
+            match visit {
+                Visit::Subtree(start, end) => {
+                    if start >= end {
+                        continue;
+                    }
+                    let root = middle(start, end);
+                    if !end_overlaps_start(self.max_ends[root].as_ref(), self.query_start.as_ref())
+                    {
+                        // The whole subtree ends before the query
+                        continue;
+                    }
+
+                    let root_start = stored_bounds(&self.entries[root].0).0;
+                    if end_overlaps_start(self.query_end.as_ref(), root_start) {
+                        self.stack.push(Visit::Subtree(root + 1, end));
+                        self.stack.push(Visit::Entry(root));
+                    }
+                    self.stack.push(Visit::Subtree(start, root));
+                }
+                Visit::Entry(position) => {
+                    let (range, value) = &self.entries[position];
+                    if range.intersects(&self.query) {
+                        return Some((range, value));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> FusedIterator for IntervalIndexIter<'_, Idx, V> {}
+
+impl<Idx: fmt::Debug, V> fmt::Debug for IntervalIndexIter<'_, Idx, V> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("IntervalIndexIter")
+            .field("query", &self.query)
+            .field("remaining_steps", &self.stack.len())
+            .finish()
+    }
+}
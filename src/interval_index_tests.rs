@@ -0,0 +1,151 @@
+use crate::ContinuousRange;
+
+fn values<'a, V: Copy + 'a>(
+    entries: impl Iterator<Item = (&'a ContinuousRange<i32>, &'a V)>,
+) -> Vec<V> {
+    entries.map(|(_, value)| *value).collect()
+}
+
+mod test_build {
+    use super::values;
+    use crate::{ContinuousRange, IntervalIndex};
+
+    #[test]
+    pub fn sorted_by_start_then_end() {
+        let index: IntervalIndex<_, _> = vec![
+            (ContinuousRange::inclusive(5, 10), 'c'),
+            (ContinuousRange::inclusive(1, 10), 'b'),
+            (ContinuousRange::inclusive(1, 3), 'a'),
+            (ContinuousRange::to(20), 'x'),
+            (ContinuousRange::start_exclusive(5, 6), 'd'),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(values(index.iter()), vec!['x', 'a', 'b', 'c', 'd']);
+        assert_eq!(index.len(), 5);
+    }
+
+    #[test]
+    pub fn empty_is_ignored() {
+        let index: IntervalIndex<_, _> = vec![
+            (ContinuousRange::empty(), 'a'),
+            (ContinuousRange::exclusive(3, 3), 'b'),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(index.is_empty(), true);
+        assert_eq!(values(index.containing(3)), Vec::<char>::new());
+    }
+
+    #[test]
+    pub fn iter() {
+        let index: IntervalIndex<_, _> = vec![
+            (ContinuousRange::inclusive(5, 10), 'b'),
+            (ContinuousRange::inclusive(1, 3), 'a'),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(index.iter().len(), 2);
+        assert_eq!(values(index.iter().rev()), vec!['b', 'a']);
+
+        let mut visited = Vec::new();
+        for (range, value) in &index {
+            visited.push((range.clone(), *value));
+        }
+        assert_eq!(
+            visited,
+            vec![
+                (ContinuousRange::inclusive(1, 3), 'a'),
+                (ContinuousRange::inclusive(5, 10), 'b'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn default() {
+        let index = IntervalIndex::<i32, char>::default();
+        assert_eq!(values(index.overlapping(&ContinuousRange::full())), vec![]);
+    }
+}
+
+mod test_overlapping {
+    use super::values;
+    use crate::{ContinuousRange, IntervalIndex};
+
+    fn index() -> IntervalIndex<i32, char> {
+        vec![
+            (ContinuousRange::end_exclusive(1, 5), 'a'),
+            (ContinuousRange::start_exclusive(5, 8), 'b'),
+            (ContinuousRange::inclusive(8, 10), 'c'),
+            (ContinuousRange::from_exclusive(20), 'd'),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    pub fn exclusive_bounds() {
+        assert_eq!(values(index().containing(5)), Vec::<char>::new());
+        assert_eq!(values(index().containing(8)), vec!['b', 'c']);
+        assert_eq!(values(index().containing(20)), Vec::<char>::new());
+        assert_eq!(
+            values(index().overlapping(&ContinuousRange::exclusive(10, 20))),
+            Vec::<char>::new()
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            values(index().overlapping(&ContinuousRange::to(1))),
+            vec!['a']
+        );
+        assert_eq!(
+            values(index().overlapping(&ContinuousRange::from(9))),
+            vec!['c', 'd']
+        );
+        assert_eq!(
+            values(index().overlapping(&ContinuousRange::full())),
+            vec!['a', 'b', 'c', 'd']
+        );
+    }
+
+    #[test]
+    pub fn empty_query() {
+        assert_eq!(
+            values(index().overlapping(&ContinuousRange::inclusive(9, 2))),
+            Vec::<char>::new()
+        );
+    }
+
+    #[test]
+    pub fn matches_linear_scan() {
+        let ranges = crate::interval_tree_tests::random_ranges(1_000);
+        let index: IntervalIndex<_, _> = ranges.iter().cloned().zip(0..).collect();
+
+        for query in crate::interval_tree_tests::random_ranges(200) {
+            let mut expected: Vec<_> = ranges
+                .iter()
+                .zip(0..)
+                .filter(|(range, _)| range.intersects(&query))
+                .map(|(_, i)| i)
+                .collect();
+            let mut actual = values(index.overlapping(&query));
+            expected.sort_unstable();
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "{query:?}");
+        }
+    }
+
+    #[test]
+    pub fn shared_between_threads() {
+        let index = &index();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|i| scope.spawn(move || values(index.containing(i * 3)).len()))
+                .collect();
+            let counts: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+            assert_eq!(counts, vec![0, 1, 1, 1]);
+        });
+    }
+}
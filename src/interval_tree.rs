@@ -0,0 +1,383 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::Bound,
+};
+
+use crate::{
+    bounds::{end_overlaps_start, max_end, partial_cmp_bounds, BoundSide},
+    entries::stored_bounds,
+    ContinuousRange,
+};
+
+type Link<Idx, V> = Option<Box<Node<Idx, V>>>;
+
+#[derive(Clone)]
+struct Node<Idx, V> {
+    range: ContinuousRange<Idx>,
+    value: V,
+    /// Greatest end bound of the ranges in this subtree
+    max_end: Bound<Idx>,
+    height: usize,
+    left: Link<Idx, V>,
+    right: Link<Idx, V>,
+}
+
+/// A collection of possibly overlapping [`ContinuousRange`]s associated with values, answering stabbing and overlap
+/// queries in O(log n + k) for k results.
+///
+/// Entries are kept in a balanced binary tree ordered by start bound then end bound, each node storing the greatest
+/// end bound of its subtree to skip the subtrees that can't contain a result. The same range can be inserted multiple
+/// times, empty ranges are ignored as they can't be returned by any query.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, IntervalTree};
+///
+/// let mut bookings = IntervalTree::new();
+/// bookings.insert(ContinuousRange::end_exclusive(9, 12), "Alice");
+/// bookings.insert(ContinuousRange::end_exclusive(11, 14), "Bob");
+/// bookings.insert(ContinuousRange::end_exclusive(14, 16), "Carol");
+///
+/// let at_11: Vec<_> = bookings.containing(11).map(|(_, name)| *name).collect();
+/// assert_eq!(at_11, vec!["Alice", "Bob"]);
+///
+/// let afternoon = ContinuousRange::end_exclusive(12, 18);
+/// let names: Vec<_> = bookings.overlapping(&afternoon).map(|(_, name)| *name).collect();
+/// assert_eq!(names, vec!["Bob", "Carol"]);
+/// ```
+#[derive(Clone)]
+pub struct IntervalTree<Idx, V> {
+    root: Link<Idx, V>,
+    len: usize,
+}
+
+/// Order ranges by start bound then end bound, both ranges must be non-empty
+pub(crate) fn cmp_ranges<Idx: Ord + Clone>(
+    a: &ContinuousRange<Idx>,
+    b: &ContinuousRange<Idx>,
+) -> Ordering {
+    let (a_start, a_end) = stored_bounds(a);
+    let (b_start, b_end) = stored_bounds(b);
+    partial_cmp_bounds(&a_start, BoundSide::Start, &b_start, BoundSide::Start)
+        .and_then(|ordering| {
+            let ends = partial_cmp_bounds(&a_end, BoundSide::End, &b_end, BoundSide::End)?;
+            Some(ordering.then(ends))
+        })
+        .expect("Bounds of totally ordered values are always comparable")
+}
+
+fn height<Idx, V>(link: &Link<Idx, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+// Rebalancing moves the boxed nodes around instead of reallocating them
+#[allow(clippy::unnecessary_box_returns)]
+impl<Idx: Ord + Clone, V> Node<Idx, V> {
+    fn new(range: ContinuousRange<Idx>, value: V) -> Self {
+        let max_end = stored_bounds(&range).1.cloned();
+        Self {
+            range,
+            value,
+            max_end,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Recompute the height and greatest end bound from the children
+    fn update(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+
+        let mut end = stored_bounds(&self.range).1;
+        for child in self.left.iter().chain(self.right.iter()) {
+            end = max_end(end, child.max_end.as_ref());
+        }
+        self.max_end = end.cloned();
+    }
+
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        let mut right = self.right.take().expect("Rotated node has a right child");
+        self.right = right.left.take();
+        self.update();
+        right.left = Some(self);
+        right.update();
+        right
+    }
+
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        let mut left = self.left.take().expect("Rotated node has a left child");
+        self.left = left.right.take();
+        self.update();
+        left.right = Some(self);
+        left.update();
+        left
+    }
+
+    /// Update the node and restore the balance of its subtree after one of its children grew or shrank by one level
+    fn balance(mut self: Box<Self>) -> Box<Self> {
+        self.update();
+        let (left, right) = (height(&self.left), height(&self.right));
+
+        if left > right + 1 {
+            let child = self.left.take().expect("Left heavy node has a left child");
+            self.left = Some(if height(&child.left) < height(&child.right) {
+                child.rotate_left()
+            } else {
+                child
+            });
+            self.rotate_right()
+        } else if right > left + 1 {
+            let child = self
+                .right
+                .take()
+                .expect("Right heavy node has a right child");
+            self.right = Some(if height(&child.right) < height(&child.left) {
+                child.rotate_right()
+            } else {
+                child
+            });
+            self.rotate_left()
+        } else {
+            self
+        }
+    }
+
+    fn insert(link: Link<Idx, V>, new: Box<Self>) -> Box<Self> {
+        match link {
+            None => new,
+            Some(mut node) => {
+                if cmp_ranges(&new.range, &node.range) == Ordering::Less {
+                    node.left = Some(Self::insert(node.left.take(), new));
+                } else {
+                    node.right = Some(Self::insert(node.right.take(), new));
+                }
+                node.balance()
+            }
+        }
+    }
+
+    /// Detach the first node of the subtree, returning the rest of the subtree and the node
+    fn take_first(mut self: Box<Self>) -> (Link<Idx, V>, Box<Self>) {
+        match self.left.take() {
+            None => (self.right.take(), self),
+            Some(left) => {
+                let (rest, first) = left.take_first();
+                self.left = rest;
+                (Some(self.balance()), first)
+            }
+        }
+    }
+
+    fn remove(link: &mut Link<Idx, V>, range: &ContinuousRange<Idx>) -> Option<V> {
+        let ordering = cmp_ranges(range, &link.as_ref()?.range);
+        let node = link.as_mut()?;
+        let removed = match ordering {
+            Ordering::Less => Self::remove(&mut node.left, range),
+            Ordering::Greater => Self::remove(&mut node.right, range),
+            Ordering::Equal => {
+                let mut node = link.take()?;
+                *link = match (node.left.take(), node.right.take()) {
+                    (None, child) | (child, None) => child,
+                    (left, Some(right)) => {
+                        let (rest, mut first) = right.take_first();
+                        first.left = left;
+                        first.right = rest;
+                        Some(first.balance())
+                    }
+                };
+                return Some(node.value);
+            }
+        };
+
+        if removed.is_some() {
+            *link = link.take().map(Node::balance);
+        }
+        removed
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> IntervalTree<Idx, V> {
+    /// Create an empty tree
+    #[must_use]
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Number of entries in the tree
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the tree contains no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add an entry to the tree, empty ranges are ignored
+    pub fn insert(&mut self, range: impl Into<ContinuousRange<Idx>>, value: V) {
+        let range = range.into();
+        if range.is_empty() {
+            return;
+        }
+
+        let new = Box::new(Node::new(range, value));
+        self.root = Some(Node::insert(self.root.take(), new));
+        self.len += 1;
+    }
+
+    /// Remove an entry with the same bounds as `range` and return its value
+    ///
+    /// When the range was inserted multiple times, only one of its entries is removed.
+    pub fn remove(&mut self, range: &ContinuousRange<Idx>) -> Option<V> {
+        if range.is_empty() {
+            return None;
+        }
+
+        let removed = Node::remove(&mut self.root, range);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Iterate over all the entries, ordered by start bound then end bound
+    #[must_use]
+    pub fn iter(&self) -> IntervalTreeIter<'_, Idx, V> {
+        self.overlapping(&ContinuousRange::Full)
+    }
+
+    /// Iterate over the entries whose range intersects `range`, ordered by start bound then end bound
+    ///
+    /// Exclusive bounds are respected like in [`ContinuousRange::intersects`]: `[1..5)` doesn't intersect `[5..8]`.
+    #[must_use]
+    pub fn overlapping(&self, range: &ContinuousRange<Idx>) -> IntervalTreeIter<'_, Idx, V> {
+        IntervalTreeIter::new(self.root.as_deref(), range.clone())
+    }
+
+    /// Iterate over the entries whose range contains `value`, ordered by start bound then end bound
+    #[must_use]
+    pub fn containing(&self, value: impl Borrow<Idx>) -> IntervalTreeIter<'_, Idx, V> {
+        self.overlapping(&ContinuousRange::Single(value.borrow().clone()))
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> Default for IntervalTree<Idx, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V: fmt::Debug> fmt::Debug for IntervalTree<Idx, V> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> FromIterator<(ContinuousRange<Idx>, V)>
+    for IntervalTree<Idx, V>
+{
+    fn from_iter<T: IntoIterator<Item = (ContinuousRange<Idx>, V)>>(iter: T) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> Extend<(ContinuousRange<Idx>, V)> for IntervalTree<Idx, V> {
+    fn extend<T: IntoIterator<Item = (ContinuousRange<Idx>, V)>>(&mut self, iter: T) {
+        for (range, value) in iter {
+            self.insert(range, value);
+        }
+    }
+}
+
+impl<'a, Idx: Ord + Clone + fmt::Debug, V> IntoIterator for &'a IntervalTree<Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+    type IntoIter = IntervalTreeIter<'a, Idx, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of an [`IntervalTree`] intersecting a range.
+///
+/// Created by [`IntervalTree::iter`], [`IntervalTree::overlapping`] and [`IntervalTree::containing`].
+#[derive(Clone)]
+pub struct IntervalTreeIter<'a, Idx, V> {
+    /// Nodes left to visit, each with its right subtree, the top being the next in order
+    stack: Vec<&'a Node<Idx, V>>,
+    query: ContinuousRange<Idx>,
+    query_start: Bound<Idx>,
+    query_end: Bound<Idx>,
+}
+
+impl<'a, Idx: Ord + Clone + fmt::Debug, V> IntervalTreeIter<'a, Idx, V> {
+    fn new(root: Option<&'a Node<Idx, V>>, query: ContinuousRange<Idx>) -> Self {
+        let bounds = query
+            .range_bounds()
+            .filter(|_| !query.is_empty())
+            .map(|(start, end)| (start.cloned(), end.cloned()));
+        let is_empty = bounds.is_none();
+        let (query_start, query_end) = bounds.unwrap_or((Bound::Unbounded, Bound::Unbounded));
+
+        let mut iter = Self {
+            stack: Vec::new(),
+            query,
+            query_start,
+            query_end,
+        };
+        if !is_empty {
+            iter.push_left(root);
+        }
+        iter
+    }
+
+    /// Push the node and its chain of left children, skipping the subtrees ending before the query
+    fn push_left(&mut self, mut link: Option<&'a Node<Idx, V>>) {
+        while let Some(node) = link {
+            if !end_overlaps_start(node.max_end.as_ref(), self.query_start.as_ref()) {
+                break;
+            }
+            self.stack.push(node);
+            link = node.left.as_deref();
+        }
+    }
+}
+
+impl<'a, Idx: Ord + Clone + fmt::Debug, V> Iterator for IntervalTreeIter<'a, Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            let start = stored_bounds(&node.range).0;
+            if !end_overlaps_start(self.query_end.as_ref(), start) {
+                // This node and all the remaining ones start after the end of the query
+                self.stack.clear();
+                return None;
+            }
+
+            self.push_left(node.right.as_deref());
+            if node.range.intersects(&self.query) {
+                return Some((&node.range, &node.value));
+            }
+        }
+        None
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V> FusedIterator for IntervalTreeIter<'_, Idx, V> {}
+
+impl<Idx: fmt::Debug, V> fmt::Debug for IntervalTreeIter<'_, Idx, V> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("IntervalTreeIter")
+            .field("query", &self.query)
+            .field("remaining_nodes", &self.stack.len())
+            .finish()
+    }
+}
@@ -0,0 +1,213 @@
+use std::convert::TryFrom;
+
+use crate::ContinuousRange;
+
+fn values<'a, V: Copy + 'a>(
+    entries: impl Iterator<Item = (&'a ContinuousRange<i32>, &'a V)>,
+) -> Vec<V> {
+    entries.map(|(_, value)| *value).collect()
+}
+
+/// Deterministic pseudo-random ranges of every shape
+pub(crate) fn random_ranges(count: usize) -> Vec<ContinuousRange<i32>> {
+    let mut state: u32 = 42;
+    let mut next = move |max: u32| {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        i32::try_from((state >> 8) % max).unwrap()
+    };
+
+    (0..count)
+        .map(|_| {
+            let start = next(100);
+            let end = start + 1 + next(20);
+            match next(10) {
+                0 => ContinuousRange::single(start),
+                1 => ContinuousRange::exclusive(start, end),
+                2 => ContinuousRange::start_exclusive(start, end),
+                3 => ContinuousRange::end_exclusive(start, end),
+                4 => ContinuousRange::to(start),
+                5 => ContinuousRange::from_exclusive(end),
+                _ => ContinuousRange::inclusive(start, end),
+            }
+        })
+        .collect()
+}
+
+mod test_insert {
+    use super::values;
+    use crate::{ContinuousRange, IntervalTree};
+
+    #[test]
+    pub fn ordered_by_start_then_end() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::inclusive(5, 10), 'c');
+        tree.insert(ContinuousRange::inclusive(1, 10), 'b');
+        tree.insert(ContinuousRange::inclusive(1, 3), 'a');
+        tree.insert(ContinuousRange::to(20), 'x');
+        tree.insert(ContinuousRange::start_exclusive(5, 6), 'd');
+        assert_eq!(values(tree.iter()), vec!['x', 'a', 'b', 'c', 'd']);
+        assert_eq!(tree.len(), 5);
+    }
+
+    #[test]
+    pub fn duplicates() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::inclusive(1, 3), 'a');
+        tree.insert(ContinuousRange::inclusive(1, 3), 'b');
+        assert_eq!(tree.len(), 2);
+        assert_eq!(values(tree.containing(2)), vec!['a', 'b']);
+    }
+
+    #[test]
+    pub fn empty_is_ignored() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::empty(), 'a');
+        tree.insert(ContinuousRange::exclusive(3, 3), 'b');
+        assert_eq!(tree.is_empty(), true);
+    }
+
+    #[test]
+    pub fn sorted_input() {
+        let tree: IntervalTree<_, _> = (0..10_000)
+            .map(|i| (ContinuousRange::inclusive(i, i + 1), i))
+            .collect();
+        assert_eq!(tree.len(), 10_000);
+        assert_eq!(values(tree.containing(5_000)), vec![4_999, 5_000]);
+    }
+}
+
+mod test_remove {
+    use super::values;
+    use crate::{ContinuousRange, IntervalTree};
+
+    #[test]
+    pub fn existing() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::inclusive(1, 3), 'a');
+        tree.insert(ContinuousRange::inclusive(2, 4), 'b');
+        tree.insert(ContinuousRange::inclusive(3, 5), 'c');
+
+        assert_eq!(tree.remove(&ContinuousRange::inclusive(2, 4)), Some('b'));
+        assert_eq!(tree.len(), 2);
+        assert_eq!(values(tree.containing(3)), vec!['a', 'c']);
+    }
+
+    #[test]
+    pub fn missing() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::inclusive(1, 3), 'a');
+        assert_eq!(tree.remove(&ContinuousRange::end_exclusive(1, 3)), None);
+        assert_eq!(tree.remove(&ContinuousRange::empty()), None);
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    pub fn duplicates_one_at_a_time() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::from(1), 'a');
+        tree.insert(ContinuousRange::from(1), 'a');
+        assert_eq!(tree.remove(&ContinuousRange::from(1)), Some('a'));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree.remove(&ContinuousRange::from(1)), Some('a'));
+        assert_eq!(tree.is_empty(), true);
+        assert_eq!(tree.remove(&ContinuousRange::from(1)), None);
+    }
+
+    #[test]
+    pub fn all() {
+        let ranges = super::random_ranges(500);
+        let mut tree: IntervalTree<_, _> = ranges.iter().cloned().zip(0..).collect();
+        for (i, range) in ranges.iter().enumerate().rev() {
+            assert_eq!(tree.remove(range).is_some(), true);
+            assert_eq!(tree.len(), i);
+        }
+        assert_eq!(tree.iter().count(), 0);
+    }
+}
+
+mod test_overlapping {
+    use super::values;
+    use crate::{ContinuousRange, IntervalTree};
+
+    fn tree() -> IntervalTree<i32, char> {
+        vec![
+            (ContinuousRange::end_exclusive(1, 5), 'a'),
+            (ContinuousRange::start_exclusive(5, 8), 'b'),
+            (ContinuousRange::inclusive(8, 10), 'c'),
+            (ContinuousRange::from_exclusive(20), 'd'),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    pub fn exclusive_bounds() {
+        assert_eq!(values(tree().containing(5)), Vec::<char>::new());
+        assert_eq!(values(tree().containing(8)), vec!['b', 'c']);
+        assert_eq!(values(tree().containing(20)), Vec::<char>::new());
+        assert_eq!(
+            values(tree().overlapping(&ContinuousRange::single(5))),
+            Vec::<char>::new()
+        );
+        assert_eq!(
+            values(tree().overlapping(&ContinuousRange::exclusive(10, 20))),
+            Vec::<char>::new()
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            values(tree().overlapping(&ContinuousRange::to(1))),
+            vec!['a']
+        );
+        assert_eq!(
+            values(tree().overlapping(&ContinuousRange::from(9))),
+            vec!['c', 'd']
+        );
+        assert_eq!(values(tree().containing(i32::MAX)), vec!['d']);
+    }
+
+    #[test]
+    pub fn empty_query() {
+        assert_eq!(
+            values(tree().overlapping(&ContinuousRange::empty())),
+            Vec::<char>::new()
+        );
+        assert_eq!(
+            values(tree().overlapping(&ContinuousRange::inclusive(9, 2))),
+            Vec::<char>::new()
+        );
+    }
+
+    #[test]
+    pub fn matches_linear_scan() {
+        let ranges = super::random_ranges(1_000);
+        let tree: IntervalTree<_, _> = ranges.iter().cloned().zip(0..).collect();
+
+        for query in super::random_ranges(200) {
+            let mut expected: Vec<_> = ranges
+                .iter()
+                .zip(0..)
+                .filter(|(range, _)| range.intersects(&query))
+                .map(|(_, i)| i)
+                .collect();
+            let mut actual = values(tree.overlapping(&query));
+            expected.sort_unstable();
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "{query:?}");
+        }
+    }
+}
+
+mod test_fmt_debug {
+    use crate::{ContinuousRange, IntervalTree};
+
+    #[test]
+    pub fn entries() {
+        let mut tree = IntervalTree::new();
+        tree.insert(ContinuousRange::from(5), 'b');
+        tree.insert(ContinuousRange::end_exclusive(1, 3), 'a');
+        assert_eq!(format!("{tree:?}"), "{[1..3): 'a', [5..): 'b'}");
+    }
+}
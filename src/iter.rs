@@ -0,0 +1,318 @@
+use std::{convert::TryFrom, iter::FusedIterator, ops::Bound, slice, vec};
+
+use crate::{
+    bounds::{end_overlaps_start, reverse_bound},
+    discrete::inclusive_bounds,
+    ContinuousRange, Discrete, Range,
+};
+
+/// Iterator over the values of a [`ContinuousRange`] of [`Discrete`] values.
+///
+/// Created by [`ContinuousRange::iter`].
+///
+/// The iterator can't produce values from an unbounded side: iterating forward over `(..5]` or backward over `[5..)`
+/// panics.
+#[derive(Clone, Debug)]
+pub struct ContinuousRangeIter<Idx> {
+    /// Next value to produce from the front, [`Bound::Included`] or [`Bound::Unbounded`]
+    front: Bound<Idx>,
+    /// Next value to produce from the back, [`Bound::Included`] or [`Bound::Unbounded`]
+    back: Bound<Idx>,
+    exhausted: bool,
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> ContinuousRangeIter<Idx> {
+    pub(crate) fn new(range: &ContinuousRange<Idx>) -> Self {
+        match inclusive_bounds(range) {
+            Some((front, back)) => Self {
+                front,
+                back,
+                exhausted: false,
+            },
+            None => Self::exhausted(),
+        }
+    }
+
+    fn exhausted() -> Self {
+        Self {
+            front: Bound::Unbounded,
+            back: Bound::Unbounded,
+            exhausted: true,
+        }
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> Iterator for ContinuousRangeIter<Idx> {
+    type Item = Idx;
+
+    fn next(&mut self) -> Option<Idx> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = match &self.front {
+            Bound::Included(value) => value.clone(),
+            _ => panic!("Can't iterate forward over a range without start bound"),
+        };
+
+        let next = match &self.back {
+            Bound::Included(back) if *back == value => None,
+            _ => value.successor(),
+        };
+
+        match next {
+            Some(next) => self.front = Bound::Included(next),
+            None => self.exhausted = true,
+        }
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted {
+            return (0, Some(0));
+        }
+
+        match (&self.front, &self.back) {
+            (Bound::Included(front), Bound::Included(back)) => {
+                let len = Idx::steps_between(front, back)
+                    .and_then(|steps| steps.checked_add(1))
+                    .and_then(|len| usize::try_from(len).ok());
+                match len {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+            // The number of values depends on the smallest or largest value of the type
+            _ => (1, None),
+        }
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> DoubleEndedIterator for ContinuousRangeIter<Idx> {
+    fn next_back(&mut self) -> Option<Idx> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = match &self.back {
+            Bound::Included(value) => value.clone(),
+            _ => panic!("Can't iterate backward over a range without end bound"),
+        };
+
+        let next = match &self.front {
+            Bound::Included(front) if *front == value => None,
+            _ => value.predecessor(),
+        };
+
+        match next {
+            Some(next) => self.back = Bound::Included(next),
+            None => self.exhausted = true,
+        }
+
+        Some(value)
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> FusedIterator for ContinuousRangeIter<Idx> {}
+
+/// Iterator over the values of a [`Range`] of [`Discrete`] values, in the order of its components.
+///
+/// Created by [`Range::iter`].
+#[derive(Clone, Debug)]
+pub struct RangeIter<Idx> {
+    front: Option<ContinuousRangeIter<Idx>>,
+    components: vec::IntoIter<ContinuousRangeIter<Idx>>,
+    back: Option<ContinuousRangeIter<Idx>>,
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> RangeIter<Idx> {
+    pub(crate) fn new(range: &Range<Idx>) -> Self {
+        let components: Vec<_> = range
+            .components()
+            .iter()
+            .map(ContinuousRangeIter::new)
+            .collect();
+
+        Self {
+            front: None,
+            components: components.into_iter(),
+            back: None,
+        }
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> Iterator for RangeIter<Idx> {
+    type Item = Idx;
+
+    fn next(&mut self) -> Option<Idx> {
+        loop {
+            if let Some(value) = self.front.as_mut().and_then(Iterator::next) {
+                return Some(value);
+            }
+
+            match self.components.next() {
+                Some(component) => self.front = Some(component),
+                None => return self.back.as_mut().and_then(Iterator::next),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.front
+            .iter()
+            .chain(self.components.as_slice())
+            .chain(self.back.iter())
+            .map(Iterator::size_hint)
+            .fold((0, Some(0)), |(lower, upper), (item_lower, item_upper)| {
+                (
+                    lower.saturating_add(item_lower),
+                    upper
+                        .zip(item_upper)
+                        .and_then(|(upper, item_upper)| upper.checked_add(item_upper)),
+                )
+            })
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> DoubleEndedIterator for RangeIter<Idx> {
+    fn next_back(&mut self) -> Option<Idx> {
+        loop {
+            if let Some(value) = self.back.as_mut().and_then(DoubleEndedIterator::next_back) {
+                return Some(value);
+            }
+
+            match self.components.next_back() {
+                Some(component) => self.back = Some(component),
+                None => return self.front.as_mut().and_then(DoubleEndedIterator::next_back),
+            }
+        }
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> FusedIterator for RangeIter<Idx> {}
+
+/// Implement [`ExactSizeIterator`] for the types whose ranges always have less than [`usize::MAX`] values, like the
+/// standard library does for [`std::ops::Range`].
+///
+/// As for [`std::ops::Range`] the length of `usize` and `isize` ranges covering every value of the type doesn't fit,
+/// and the length of ranges that are unbounded on a side isn't known: [`ExactSizeIterator::len`] panics for them.
+macro_rules! impl_exact_size {
+    ($($t:ty),*) => {
+        $(
+            impl ExactSizeIterator for ContinuousRangeIter<$t> {}
+            impl ExactSizeIterator for RangeIter<$t> {}
+        )*
+    };
+}
+
+impl_exact_size!(u8, u16, u32, usize, i8, i16, i32, isize, char);
+
+impl<Idx: Discrete + PartialOrd + Clone> IntoIterator for ContinuousRange<Idx> {
+    type Item = Idx;
+    type IntoIter = ContinuousRangeIter<Idx>;
+
+    fn into_iter(self) -> ContinuousRangeIter<Idx> {
+        ContinuousRangeIter::new(&self)
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> IntoIterator for &ContinuousRange<Idx> {
+    type Item = Idx;
+    type IntoIter = ContinuousRangeIter<Idx>;
+
+    fn into_iter(self) -> ContinuousRangeIter<Idx> {
+        ContinuousRangeIter::new(self)
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> IntoIterator for Range<Idx> {
+    type Item = Idx;
+    type IntoIter = RangeIter<Idx>;
+
+    fn into_iter(self) -> RangeIter<Idx> {
+        RangeIter::new(&self)
+    }
+}
+
+impl<Idx: Discrete + PartialOrd + Clone> IntoIterator for &Range<Idx> {
+    type Item = Idx;
+    type IntoIter = RangeIter<Idx>;
+
+    fn into_iter(self) -> RangeIter<Idx> {
+        RangeIter::new(self)
+    }
+}
+
+/// Iterator over the parts of a [`ContinuousRange`] that aren't covered by a sorted slice of ranges, in order.
+///
+/// Created by [`ContinuousRange::gaps`].
+#[derive(Clone, Debug)]
+pub struct GapsIter<'a, Idx> {
+    ranges: slice::Iter<'a, ContinuousRange<Idx>>,
+    /// Start bound of the next gap, [None] once the end of the bounding range is reached
+    gap_start: Option<Bound<Idx>>,
+    end: Bound<Idx>,
+}
+
+impl<'a, Idx: PartialOrd + Clone> GapsIter<'a, Idx> {
+    pub(crate) fn new(within: &ContinuousRange<Idx>, ranges: &'a [ContinuousRange<Idx>]) -> Self {
+        let (gap_start, end) = match within.range_bounds().filter(|_| !within.is_empty()) {
+            Some((start, end)) => (Some(start.cloned()), end.cloned()),
+            None => (None, Bound::Unbounded),
+        };
+
+        Self {
+            ranges: ranges.iter(),
+            gap_start,
+            end,
+        }
+    }
+}
+
+impl<Idx: PartialOrd + Clone> Iterator for GapsIter<'_, Idx> {
+    type Item = ContinuousRange<Idx>;
+
+    fn next(&mut self) -> Option<ContinuousRange<Idx>> {
+        while let Some(gap_start) = self.gap_start.take() {
+            let Some(range) = self.ranges.next() else {
+                // Nothing covers the rest of the bounding range
+                return Some(ContinuousRange::from_bounds((
+                    gap_start.as_ref(),
+                    self.end.as_ref(),
+                )))
+                .filter(|gap| !gap.is_empty());
+            };
+            let Some((start, end)) = range.range_bounds().filter(|_| !range.is_empty()) else {
+                self.gap_start = Some(gap_start);
+                continue;
+            };
+
+            if !end_overlaps_start(self.end.as_ref(), start) {
+                // The range and the following ones start after the end of the bounding range
+                self.ranges = [].iter();
+                self.gap_start = Some(gap_start);
+                continue;
+            }
+            if !end_overlaps_start(end, gap_start.as_ref()) {
+                // The range ends before the gap, covered by a previous range or outside the bounding range
+                self.gap_start = Some(gap_start);
+                continue;
+            }
+
+            self.gap_start = match end {
+                Bound::Unbounded => None,
+                end => Some(reverse_bound(end).cloned()),
+            };
+            if start != Bound::Unbounded {
+                let gap = ContinuousRange::from_bounds((gap_start.as_ref(), reverse_bound(start)));
+                if !gap.is_empty() {
+                    return Some(gap);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<Idx: PartialOrd + Clone> FusedIterator for GapsIter<'_, Idx> {}
@@ -5,12 +5,41 @@
 )]
 
 mod continuous;
+mod convert;
+mod coverage;
+mod discrete;
+mod entries;
+mod interval_index;
+mod interval_tree;
+mod iter;
+mod notation;
+mod operators;
+mod partition;
 mod range;
+mod range_map;
+mod range_set;
 mod relation;
 mod bounds;
 
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use bounds::ContinuousRangeBounds;
 pub use continuous::ContinuousRange;
+pub use convert::TryFromRangeError;
+pub use coverage::Coverage;
+pub use discrete::Discrete;
+pub use interval_index::{IntervalIndex, IntervalIndexEntries, IntervalIndexIter};
+pub use interval_tree::{IntervalTree, IntervalTreeIter};
+pub use iter::{ContinuousRangeIter, GapsIter, RangeIter};
+pub use notation::{Notation, ParseRangeError, ParseRangeErrorKind, RangeDisplay};
+pub use partition::Partition;
 pub use range::Range;
+pub use range_map::{RangeMap, RangeMapIter};
+pub use range_set::{RangeSet, RangeSetIter};
 pub use relation::RangesRelation;
 
 #[cfg(test)]
@@ -18,3 +47,33 @@ mod tests;
 
 #[cfg(test)]
 mod continuous_tests;
+
+#[cfg(test)]
+mod coverage_tests;
+
+#[cfg(test)]
+mod discrete_tests;
+
+#[cfg(test)]
+mod interval_index_tests;
+
+#[cfg(test)]
+mod interval_tree_tests;
+
+#[cfg(test)]
+mod notation_tests;
+
+#[cfg(test)]
+mod partition_tests;
+
+#[cfg(test)]
+mod range_map_tests;
+
+#[cfg(test)]
+mod range_set_tests;
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests;
+
+#[cfg(all(test, feature = "postgres"))]
+mod postgres_tests;
@@ -0,0 +1,711 @@
+//! Text notations of ranges: formatting in multiple notations with [`RangeDisplay`] and parsing the notation used by
+//! their [`fmt::Debug`] implementation: `[1..5)`, `(..3]`, `(..)`, `[]`, `5` or `{[1..2]; 5}` for composite ranges.
+
+use std::{
+    error::Error,
+    fmt::{self, Write},
+    ops::Bound,
+    str::FromStr,
+};
+
+use crate::{bounds::BoundSide, ContinuousRange, Range};
+
+/// Notations supported by [`RangeDisplay`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// The notation of the crate, also used by [`fmt::Debug`] and parsed by [`FromStr`]
+    ///
+    /// `[1..5)`, `(..3]`, `(..)`, `[]`, `5` or `{[1..2]; 5}`, values that could be mistaken for a part of the notation
+    /// are quoted like `["a..b"..c]`
+    #[default]
+    Native,
+
+    /// PostgreSQL range and multirange literals, values containing special characters or whitespace are quoted
+    ///
+    /// `[1,5)`, `(,3]`, `(,)`, `empty`, `[5,5]` or `{[1,2], [5,5]}`, a [`Range`] is always written as a multirange
+    /// like `{[1,5)}` or `{}`
+    PostgreSql,
+
+    /// Mathematical interval notation
+    ///
+    /// `[1, 5)`, `(-∞, 3]`, `(-∞, +∞)`, `∅`, `{5}` or `[1, 2] ∪ {5}`
+    Interval,
+
+    /// Rust syntax, a range with an exclusive start has no range syntax and is written as a tuple of bounds
+    ///
+    /// `1..5`, `..=3`, `..`, `[]`, `5..=5`, `(Excluded(1), Included(5))` or `[1..=2, 5..=5]`
+    Rust,
+}
+
+/// Formatter for ranges using a selectable [`Notation`]
+///
+/// Created by [`ContinuousRange::display`] and [`Range::display`], values are formatted using [`fmt::Display`].
+///
+/// ```
+/// use range_ranger::{ContinuousRange, Notation};
+///
+/// let range = ContinuousRange::end_exclusive(1, 5);
+/// assert_eq!(range.display().to_string(), "[1..5)");
+/// assert_eq!(range.display().notation(Notation::PostgreSql).to_string(), "[1,5)");
+/// assert_eq!(range.display().notation(Notation::Interval).to_string(), "[1, 5)");
+/// assert_eq!(range.display().notation(Notation::Rust).to_string(), "1..5");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RangeDisplay<'a, T> {
+    range: &'a T,
+    notation: Notation,
+}
+
+impl<'a, T> RangeDisplay<'a, T> {
+    pub(crate) fn new(range: &'a T) -> Self {
+        Self {
+            range,
+            notation: Notation::default(),
+        }
+    }
+
+    /// Use the specified notation
+    #[must_use]
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+}
+
+fn bound_value<Idx>(bound: Bound<&Idx>) -> Option<&Idx> {
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) => Some(value),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Write a range as `[start<separator>end)`, the brackets depending on the bounds being included or not
+fn fmt_bracketed<Idx>(
+    fmt: &mut fmt::Formatter<'_>,
+    (start, end): (Bound<&Idx>, Bound<&Idx>),
+    separator: &str,
+    mut fmt_bound: impl FnMut(&mut fmt::Formatter<'_>, Option<&Idx>, BoundSide) -> fmt::Result,
+) -> fmt::Result {
+    fmt.write_str(if let Bound::Included(_) = start {
+        "["
+    } else {
+        "("
+    })?;
+    fmt_bound(fmt, bound_value(start), BoundSide::Start)?;
+    fmt.write_str(separator)?;
+    fmt_bound(fmt, bound_value(end), BoundSide::End)?;
+    fmt.write_str(if let Bound::Included(_) = end {
+        "]"
+    } else {
+        ")"
+    })
+}
+
+/// Write `text` between quotes, escaping the quotes and backslashes it contains
+fn fmt_quoted(fmt: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    fmt.write_char('"')?;
+    for c in text.chars() {
+        if c == '"' || c == '\\' {
+            fmt.write_char('\\')?;
+        }
+        fmt.write_char(c)?;
+    }
+    fmt.write_char('"')
+}
+
+/// Write a value of the native notation, quoting it if it could be mistaken for a part of the notation
+fn fmt_native_value<Idx: fmt::Display>(fmt: &mut fmt::Formatter<'_>, value: &Idx) -> fmt::Result {
+    let text = value.to_string();
+    let needs_quotes = text.is_empty()
+        || text.contains("..")
+        || text.starts_with(|c: char| c == '.' || c.is_whitespace())
+        || text.ends_with(|c: char| c == '.' || c.is_whitespace())
+        || text.chars().any(|c| "\"\\;[](){}".contains(c));
+
+    if needs_quotes {
+        fmt_quoted(fmt, &text)
+    } else {
+        // Formatted again to respect the options of the formatter, like the precision
+        value.fmt(fmt)
+    }
+}
+
+/// Write a value of a PostgreSQL range literal, quoting it if needed
+fn fmt_postgresql_value<Idx: fmt::Display>(
+    fmt: &mut fmt::Formatter<'_>,
+    value: &Idx,
+) -> fmt::Result {
+    let text = value.to_string();
+    let needs_quotes = text.is_empty()
+        || text
+            .chars()
+            .any(|c| c.is_whitespace() || "\"\\,()[]{}".contains(c));
+
+    if needs_quotes {
+        fmt_quoted(fmt, &text)
+    } else {
+        fmt.write_str(&text)
+    }
+}
+
+fn fmt_rust_bound<Idx: fmt::Display>(
+    fmt: &mut fmt::Formatter<'_>,
+    bound: Bound<&Idx>,
+) -> fmt::Result {
+    match bound {
+        Bound::Included(value) => write!(fmt, "Included({value})"),
+        Bound::Excluded(value) => write!(fmt, "Excluded({value})"),
+        Bound::Unbounded => write!(fmt, "Unbounded"),
+    }
+}
+
+impl<Idx: fmt::Display + PartialOrd> fmt::Display for RangeDisplay<'_, ContinuousRange<Idx>> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // PostgreSQL rejects inverted bounds, ranges that are empty because of them are written as `empty`
+        let bounds = self
+            .range
+            .range_bounds()
+            .filter(|_| self.notation != Notation::PostgreSql || !self.range.is_empty());
+        let Some((start, end)) = bounds else {
+            return fmt.write_str(match self.notation {
+                Notation::Native | Notation::Rust => "[]",
+                Notation::PostgreSql => "empty",
+                Notation::Interval => "∅",
+            });
+        };
+
+        match (self.notation, self.range) {
+            (Notation::Native, ContinuousRange::Single(value)) => fmt_native_value(fmt, value),
+            (Notation::Native, _) => fmt_bracketed(fmt, (start, end), "..", |fmt, value, _| {
+                value.map_or(Ok(()), |value| fmt_native_value(fmt, value))
+            }),
+            (Notation::PostgreSql, _) => fmt_bracketed(fmt, (start, end), ",", |fmt, value, _| {
+                value.map_or(Ok(()), |value| fmt_postgresql_value(fmt, value))
+            }),
+            (Notation::Interval, ContinuousRange::Single(value)) => {
+                fmt.write_char('{')?;
+                value.fmt(fmt)?;
+                fmt.write_char('}')
+            }
+            (Notation::Interval, _) => {
+                fmt_bracketed(fmt, (start, end), ", ", |fmt, value, side| {
+                    match (value, side) {
+                        (Some(value), _) => value.fmt(fmt),
+                        (None, BoundSide::Start) => fmt.write_str("-∞"),
+                        (None, BoundSide::End) => fmt.write_str("+∞"),
+                    }
+                })
+            }
+            (Notation::Rust, _) => {
+                if let Bound::Excluded(_) = start {
+                    fmt.write_char('(')?;
+                    fmt_rust_bound(fmt, start)?;
+                    fmt.write_str(", ")?;
+                    fmt_rust_bound(fmt, end)?;
+                    return fmt.write_char(')');
+                }
+
+                if let Some(value) = bound_value(start) {
+                    value.fmt(fmt)?;
+                }
+                fmt.write_str(if let Bound::Included(_) = end {
+                    "..="
+                } else {
+                    ".."
+                })?;
+                if let Some(value) = bound_value(end) {
+                    value.fmt(fmt)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<Idx: fmt::Display + PartialOrd> fmt::Display for RangeDisplay<'_, Range<Idx>> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = match (self.range, self.notation) {
+            // PostgreSQL multirange literals are always written in braces, even with a single range or none
+            (Range::Continuous(r), Notation::PostgreSql) => std::slice::from_ref(r),
+            (Range::Continuous(r), _) => {
+                return RangeDisplay::new(r).notation(self.notation).fmt(fmt)
+            }
+            (Range::Composite(items), _) => items,
+        };
+
+        let (open, separator, close) = match self.notation {
+            Notation::Native => ("{", "; ", "}"),
+            Notation::PostgreSql => ("{", ", ", "}"),
+            Notation::Interval if items.is_empty() => return fmt.write_str("∅"),
+            Notation::Interval => ("", " ∪ ", ""),
+            Notation::Rust => ("[", ", ", "]"),
+        };
+
+        fmt.write_str(open)?;
+        let mut first = true;
+        for item in items {
+            // Empty ranges aren't allowed in PostgreSQL multiranges
+            if self.notation == Notation::PostgreSql && item.is_empty() {
+                continue;
+            }
+
+            if !first {
+                fmt.write_str(separator)?;
+            }
+            first = false;
+            RangeDisplay::new(item).notation(self.notation).fmt(fmt)?;
+        }
+        fmt.write_str(close)
+    }
+}
+
+/// An error returned when parsing a range from its text notation, `E` is the error type of the values parser
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseRangeError<E> {
+    position: usize,
+    kind: ParseRangeErrorKind<E>,
+}
+
+/// The cause of a [`ParseRangeError`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseRangeErrorKind<E> {
+    /// A range or a value was expected but nothing was found
+    Empty,
+    /// The range doesn't start with an opening bracket, `[` or `(`
+    MissingOpeningBracket,
+    /// The range doesn't end with a closing bracket, `]` or `)`
+    MissingClosingBracket,
+    /// The composite range doesn't start with an opening brace, `{`
+    MissingOpeningBrace,
+    /// The composite range doesn't end with a closing brace, `}`
+    MissingClosingBrace,
+    /// The range doesn't contain the separator between its bounds, `..` or `,` for PostgreSQL
+    MissingSeparator,
+    /// A quoted value doesn't end with a quote
+    UnterminatedQuote,
+    /// Unexpected characters were found after the end of the range
+    TrailingCharacters,
+    /// A value couldn't be parsed
+    InvalidValue(E),
+}
+
+impl<E> ParseRangeError<E> {
+    fn new(position: usize, kind: ParseRangeErrorKind<E>) -> Self {
+        Self { position, kind }
+    }
+
+    /// Byte offset in the input where the error was found
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The cause of the error
+    #[must_use]
+    pub fn kind(&self) -> &ParseRangeErrorKind<E> {
+        &self.kind
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseRangeErrorKind<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRangeErrorKind::Empty => write!(fmt, "expected a range"),
+            ParseRangeErrorKind::MissingOpeningBracket => write!(fmt, "expected `[` or `(`"),
+            ParseRangeErrorKind::MissingClosingBracket => write!(fmt, "expected `]` or `)`"),
+            ParseRangeErrorKind::MissingOpeningBrace => write!(fmt, "expected `{{`"),
+            ParseRangeErrorKind::MissingClosingBrace => write!(fmt, "expected `}}`"),
+            ParseRangeErrorKind::MissingSeparator => {
+                write!(fmt, "expected a separator between bounds")
+            }
+            ParseRangeErrorKind::UnterminatedQuote => write!(fmt, "unterminated quoted value"),
+            ParseRangeErrorKind::TrailingCharacters => {
+                write!(fmt, "unexpected characters after the range")
+            }
+            ParseRangeErrorKind::InvalidValue(message) => write!(fmt, "invalid value: {message}"),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseRangeError<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl<E: Error + 'static> Error for ParseRangeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseRangeErrorKind::InvalidValue(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parser for the native and PostgreSQL text notations
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn error<E>(&self, kind: ParseRangeErrorKind<E>) -> ParseRangeError<E> {
+        ParseRangeError::new(self.position, kind)
+    }
+
+    /// Consume `c` if it's the next character
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.position += c.len_utf8();
+        }
+        found
+    }
+
+    /// Consume `text` if the input continues with it
+    fn eat_str(&mut self, text: &str) -> bool {
+        let found = self.rest().starts_with(text);
+        if found {
+            self.position += text.len();
+        }
+        found
+    }
+
+    fn expect_end<E>(&mut self) -> Result<(), ParseRangeError<E>> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(ParseRangeErrorKind::TrailingCharacters))
+        }
+    }
+
+    /// Parse a value that ends before one of `terminators` outside of quotes, or at the end of the input if
+    /// `until_end` is set.
+    ///
+    /// Values can be quoted with `"` and characters escaped with `\`, inside quotes `""` is a quote. Returns [None]
+    /// if there is no value, meaning that the bound is infinite.
+    fn value<Idx: FromStr>(
+        &mut self,
+        terminators: &[&str],
+        until_end: bool,
+    ) -> Result<Option<Idx>, ParseRangeError<Idx::Err>> {
+        let raw = self.raw_value(terminators, until_end)?;
+        parse_value(raw)
+    }
+
+    /// Read a value like [`Parser::value`] without parsing it, returning its text and position
+    fn raw_value<E>(
+        &mut self,
+        terminators: &[&str],
+        until_end: bool,
+    ) -> Result<Option<(String, usize)>, ParseRangeError<E>> {
+        self.skip_whitespace();
+        let start = self.position;
+        let mut value = String::new();
+        let mut quoted = false;
+        let mut in_quotes = false;
+        // Length of the value up to the last quoted or escaped character, that can't be trimmed
+        let mut literal_len = 0;
+
+        loop {
+            let Some(c) = self.peek() else {
+                if in_quotes {
+                    return Err(self.error(ParseRangeErrorKind::UnterminatedQuote));
+                }
+                if until_end {
+                    break;
+                }
+                return Err(self.error(ParseRangeErrorKind::MissingClosingBracket));
+            };
+
+            if !in_quotes && terminators.iter().any(|t| self.rest().starts_with(t)) {
+                break;
+            }
+
+            self.position += c.len_utf8();
+            match c {
+                '\\' => {
+                    if let Some(escaped) = self.peek() {
+                        self.position += escaped.len_utf8();
+                        value.push(escaped);
+                    }
+                }
+                '"' if in_quotes && self.peek() == Some('"') => {
+                    self.position += 1;
+                    value.push('"');
+                }
+                '"' => {
+                    quoted = true;
+                    in_quotes = !in_quotes;
+                }
+                c => value.push(c),
+            }
+
+            if in_quotes || c == '"' || c == '\\' {
+                literal_len = value.len();
+            }
+        }
+
+        let trimmed_len = value.trim_end().len().max(literal_len);
+        value.truncate(trimmed_len);
+        if value.is_empty() && !quoted {
+            return Ok(None);
+        }
+        Ok(Some((value, start)))
+    }
+
+    /// Parse the opening bracket of a range, returning if the start is inclusive
+    fn opening_bracket<E>(&mut self) -> Result<bool, ParseRangeError<E>> {
+        if self.eat('[') {
+            Ok(true)
+        } else if self.eat('(') {
+            Ok(false)
+        } else {
+            Err(self.error(ParseRangeErrorKind::MissingOpeningBracket))
+        }
+    }
+
+    /// Parse the closing bracket of a range, returning if the end is inclusive
+    fn closing_bracket<E>(&mut self) -> Result<bool, ParseRangeError<E>> {
+        if self.eat(']') {
+            Ok(true)
+        } else if self.eat(')') {
+            Ok(false)
+        } else {
+            Err(self.error(ParseRangeErrorKind::MissingClosingBracket))
+        }
+    }
+
+    /// Parse a range of the native notation, in a composite range a single value ends at `;` or `}`
+    fn native_range<Idx>(
+        &mut self,
+        in_composite: bool,
+    ) -> Result<ContinuousRange<Idx>, ParseRangeError<Idx::Err>>
+    where
+        Idx: FromStr + PartialOrd + Clone,
+    {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            return Err(self.error(ParseRangeErrorKind::Empty));
+        }
+
+        if !matches!(self.peek(), Some('[' | '(')) {
+            let terminators: &[&str] = if in_composite { &[";", "}"] } else { &[] };
+            return match self.value(terminators, true)? {
+                Some(value) => Ok(ContinuousRange::Single(value)),
+                None => Err(self.error(ParseRangeErrorKind::Empty)),
+            };
+        }
+
+        let start_inclusive = self.opening_bracket()?;
+        if start_inclusive {
+            self.skip_whitespace();
+            if self.eat(']') {
+                return Ok(ContinuousRange::Empty);
+            }
+        }
+
+        // Values are parsed once the structure is known to be valid, `[1, 5]` is missing a separator
+        let start = self.raw_value(&["..", "]", ")"], false)?;
+        if !self.eat_str("..") {
+            return Err(self.error(ParseRangeErrorKind::MissingSeparator));
+        }
+        let end = self.raw_value(&["]", ")"], false)?;
+        let end_inclusive = self.closing_bracket()?;
+
+        Ok(range_from_values(
+            (parse_value(start)?, start_inclusive),
+            (parse_value(end)?, end_inclusive),
+        ))
+    }
+
+    fn postgresql_range<Idx>(&mut self) -> Result<ContinuousRange<Idx>, ParseRangeError<Idx::Err>>
+    where
+        Idx: FromStr + PartialOrd + Clone,
+    {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            return Err(self.error(ParseRangeErrorKind::Empty));
+        }
+
+        let empty = self.rest().get(..5);
+        if empty.is_some_and(|empty| empty.eq_ignore_ascii_case("empty")) {
+            self.position += 5;
+            return Ok(ContinuousRange::Empty);
+        }
+
+        let start_inclusive = self.opening_bracket()?;
+        let start = self.value(&[",", "]", ")"], false)?;
+        if !self.eat(',') {
+            return Err(self.error(ParseRangeErrorKind::MissingSeparator));
+        }
+        let end = self.value(&[",", "]", ")"], false)?;
+        let end_inclusive = self.closing_bracket()?;
+
+        Ok(range_from_values(
+            (start, start_inclusive),
+            (end, end_inclusive),
+        ))
+    }
+
+    /// Parse the ranges of a composite range between braces, separated by `separator`
+    fn composite<Idx>(
+        &mut self,
+        separator: char,
+        mut range: impl FnMut(&mut Self) -> Result<ContinuousRange<Idx>, ParseRangeError<Idx::Err>>,
+    ) -> Result<Vec<Range<Idx>>, ParseRangeError<Idx::Err>>
+    where
+        Idx: FromStr,
+    {
+        self.skip_whitespace();
+        if !self.eat('{') {
+            return Err(self.error(ParseRangeErrorKind::MissingOpeningBrace));
+        }
+
+        let mut ranges = Vec::new();
+        self.skip_whitespace();
+        if self.eat('}') {
+            return Ok(ranges);
+        }
+
+        loop {
+            ranges.push(Range::Continuous(range(self)?));
+            self.skip_whitespace();
+            if self.eat('}') {
+                return Ok(ranges);
+            }
+            if !self.eat(separator) {
+                return Err(self.error(ParseRangeErrorKind::MissingClosingBrace));
+            }
+        }
+    }
+}
+
+/// Parse a value read by [`Parser::raw_value`]
+fn parse_value<Idx: FromStr>(
+    raw: Option<(String, usize)>,
+) -> Result<Option<Idx>, ParseRangeError<Idx::Err>> {
+    raw.map(|(value, position)| {
+        value
+            .parse()
+            .map_err(|e| ParseRangeError::new(position, ParseRangeErrorKind::InvalidValue(e)))
+    })
+    .transpose()
+}
+
+/// Build a range from its parsed bound values, a missing value being an infinite bound
+fn range_from_values<Idx: PartialOrd + Clone>(
+    (start, start_inclusive): (Option<Idx>, bool),
+    (end, end_inclusive): (Option<Idx>, bool),
+) -> ContinuousRange<Idx> {
+    let start = match start {
+        Some(value) if start_inclusive => Bound::Included(value),
+        Some(value) => Bound::Excluded(value),
+        None => Bound::Unbounded,
+    };
+    let end = match end {
+        Some(value) if end_inclusive => Bound::Included(value),
+        Some(value) => Bound::Excluded(value),
+        None => Bound::Unbounded,
+    };
+
+    ContinuousRange::from_bounds((start.as_ref(), end.as_ref()))
+}
+
+/// Parse the notation used by the [`fmt::Debug`] implementation: `[1..5)`, `(..3]`, `(..)`, `[]` or a single value
+/// like `5`.
+///
+/// Whitespace around bounds is ignored and an unbounded side accepts either bracket, so `[..5]` is the same as
+/// `(..5]`. Values can be quoted with `"` and characters escaped with `\`, like `["a..b".."c"]`.
+///
+/// ```
+/// use range_ranger::ContinuousRange;
+///
+/// let range: ContinuousRange<i32> = "[1..5)".parse().unwrap();
+/// assert_eq!(range, ContinuousRange::end_exclusive(1, 5));
+/// ```
+impl<Idx: FromStr + PartialOrd + Clone> FromStr for ContinuousRange<Idx> {
+    type Err = ParseRangeError<Idx::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let range = parser.native_range(false)?;
+        parser.expect_end()?;
+        Ok(range)
+    }
+}
+
+/// Parse the notation used by the [`fmt::Debug`] implementation, composite ranges are written `{[1..2]; 5}` and the
+/// result is simplified.
+///
+/// ```
+/// use range_ranger::Range;
+///
+/// let range: Range<i32> = "{[1..2]; 5}".parse().unwrap();
+/// assert_eq!(range, Range::composite(vec![Range::continuous(1, 2), Range::single(5)]));
+/// ```
+impl<Idx: FromStr + PartialOrd + Clone + fmt::Debug> FromStr for Range<Idx> {
+    type Err = ParseRangeError<Idx::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        parser.skip_whitespace();
+        let range = if parser.peek() == Some('{') {
+            Range::composite(parser.composite(';', |parser| parser.native_range(true))?)
+        } else {
+            Range::Continuous(parser.native_range(false)?)
+        };
+        parser.expect_end()?;
+        Ok(range)
+    }
+}
+
+/// Parse a PostgreSQL range literal like `[1,5)`, `(,3]` or `empty`
+pub(crate) fn parse_postgresql_range<Idx>(
+    input: &str,
+) -> Result<ContinuousRange<Idx>, ParseRangeError<Idx::Err>>
+where
+    Idx: FromStr + PartialOrd + Clone,
+{
+    let mut parser = Parser::new(input);
+    let range = parser.postgresql_range()?;
+    parser.expect_end()?;
+    Ok(range)
+}
+
+/// Parse a PostgreSQL multirange literal like `{[1,3), [5,7)}`, a range literal is also accepted
+pub(crate) fn parse_postgresql_multirange<Idx>(
+    input: &str,
+) -> Result<Range<Idx>, ParseRangeError<Idx::Err>>
+where
+    Idx: FromStr + PartialOrd + Clone + fmt::Debug,
+{
+    let mut parser = Parser::new(input);
+    parser.skip_whitespace();
+    let range = if parser.peek() == Some('{') {
+        Range::composite(parser.composite(',', Parser::postgresql_range)?)
+    } else {
+        Range::Continuous(parser.postgresql_range()?)
+    };
+    parser.expect_end()?;
+    Ok(range)
+}
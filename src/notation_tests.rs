@@ -0,0 +1,638 @@
+mod test_parse_continuous {
+    use crate::ContinuousRange;
+
+    fn parse(s: &str) -> ContinuousRange<i32> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    pub fn all_variants() {
+        assert_eq!(parse("[]"), ContinuousRange::empty());
+        assert_eq!(parse("5"), ContinuousRange::single(5));
+        assert_eq!(parse("(..)"), ContinuousRange::full());
+        assert_eq!(parse("[1..5]"), ContinuousRange::inclusive(1, 5));
+        assert_eq!(parse("(1..5)"), ContinuousRange::exclusive(1, 5));
+        assert_eq!(parse("(1..5]"), ContinuousRange::start_exclusive(1, 5));
+        assert_eq!(parse("[1..5)"), ContinuousRange::end_exclusive(1, 5));
+        assert_eq!(parse("[1..)"), ContinuousRange::from(1));
+        assert_eq!(parse("(1..)"), ContinuousRange::from_exclusive(1));
+        assert_eq!(parse("(..3]"), ContinuousRange::to(3));
+        assert_eq!(parse("(..3)"), ContinuousRange::to_exclusive(3));
+    }
+
+    #[test]
+    pub fn round_trip_debug() {
+        let ranges = [
+            ContinuousRange::empty(),
+            ContinuousRange::single(-5),
+            ContinuousRange::full(),
+            ContinuousRange::inclusive(-10, -5),
+            ContinuousRange::start_exclusive(1, 5),
+            ContinuousRange::from_exclusive(0),
+            ContinuousRange::to_exclusive(3),
+        ];
+
+        for range in ranges {
+            assert_eq!(parse(&format!("{range:?}")), range);
+        }
+    }
+
+    #[test]
+    pub fn whitespace() {
+        assert_eq!(
+            parse("  [ 1 .. 5 )  "),
+            ContinuousRange::end_exclusive(1, 5)
+        );
+        assert_eq!(parse(" 5 "), ContinuousRange::single(5));
+    }
+
+    #[test]
+    pub fn unbounded_side_accepts_any_bracket() {
+        assert_eq!(parse("[..3]"), ContinuousRange::to(3));
+        assert_eq!(parse("[1..]"), ContinuousRange::from(1));
+        assert_eq!(parse("[..]"), ContinuousRange::full());
+    }
+
+    #[test]
+    pub fn reversed_bounds_are_empty() {
+        assert_eq!(parse("[5..1]"), ContinuousRange::empty());
+    }
+
+    #[test]
+    pub fn other_types() {
+        assert_eq!(
+            "[0.5..1.5)".parse::<ContinuousRange<f64>>().unwrap(),
+            ContinuousRange::end_exclusive(0.5, 1.5)
+        );
+        assert_eq!(
+            "[a..z]".parse::<ContinuousRange<char>>().unwrap(),
+            ContinuousRange::inclusive('a', 'z')
+        );
+    }
+
+    #[test]
+    pub fn quoted() {
+        let parse = |s: &str| s.parse::<ContinuousRange<String>>().unwrap();
+        assert_eq!(
+            parse(r#"["a..b".."c"]"#),
+            ContinuousRange::inclusive("a..b".to_string(), "c".to_string())
+        );
+        assert_eq!(
+            parse(r"[a\..b..c)"),
+            ContinuousRange::end_exclusive("a..b".to_string(), "c".to_string())
+        );
+        assert_eq!(
+            parse(r#"(.." x ")"#),
+            ContinuousRange::to_exclusive(" x ".to_string())
+        );
+        assert_eq!(
+            parse(r#""[1..5]""#),
+            ContinuousRange::single("[1..5]".to_string())
+        );
+        assert_eq!(
+            parse(r#"["".."\"\\"]"#),
+            ContinuousRange::inclusive(String::new(), "\"\\".to_string())
+        );
+    }
+
+    #[test]
+    pub fn round_trip_display_special_values() {
+        let values = [
+            "a..b",
+            "c;d",
+            "[x]",
+            "(y)",
+            "{z}",
+            "",
+            " lead",
+            "trail ",
+            ".dot",
+            "dot.",
+            "q\"uote",
+            "back\\slash",
+            "in side",
+        ];
+
+        for start in values {
+            for end in values {
+                let range = ContinuousRange::end_exclusive(start.to_string(), end.to_string());
+                assert_eq!(
+                    range
+                        .to_string()
+                        .parse::<ContinuousRange<String>>()
+                        .unwrap(),
+                    range
+                );
+            }
+            let range = ContinuousRange::Single(start.to_string());
+            assert_eq!(
+                range
+                    .to_string()
+                    .parse::<ContinuousRange<String>>()
+                    .unwrap(),
+                range
+            );
+        }
+    }
+}
+
+mod test_parse_range {
+    use crate::{ContinuousRange, Range};
+
+    fn parse(s: &str) -> Range<i32> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    pub fn continuous() {
+        assert_eq!(parse("[1..5)"), Range::continuous_end_exclusive(1, 5));
+        assert_eq!(parse("(..)"), Range::full());
+    }
+
+    #[test]
+    pub fn composite() {
+        assert_eq!(
+            parse("{[1..2]; 5}"),
+            Range::Composite(vec![
+                ContinuousRange::inclusive(1, 2),
+                ContinuousRange::single(5)
+            ])
+        );
+        assert_eq!(
+            parse(" { (..0) ;[10..) } "),
+            Range::composite(vec![Range::to_exclusive(0), Range::from(10)])
+        );
+    }
+
+    #[test]
+    pub fn composite_is_simplified() {
+        assert_eq!(parse("{[3..5]; [1..3)}"), Range::continuous(1, 5));
+        assert_eq!(parse("{}"), Range::empty());
+        assert_eq!(parse("{7}"), Range::single(7));
+    }
+
+    #[test]
+    pub fn round_trip_debug() {
+        let range = Range::composite(vec![
+            Range::to(-10),
+            Range::continuous_exclusive(0, 5),
+            Range::single(7),
+            Range::from_exclusive(10),
+        ]);
+        assert_eq!(parse(&format!("{range:?}")), range);
+    }
+
+    #[test]
+    pub fn round_trip_display_special_values() {
+        let range = Range::Composite(vec![
+            ContinuousRange::end_exclusive("a;b".to_string(), "c..d".to_string()),
+            ContinuousRange::single("d}".to_string()),
+            ContinuousRange::from("x]".to_string()),
+        ]);
+        let text = range.to_string();
+        assert_eq!(text, r#"{["a;b".."c..d"); "d}"; ["x]"..)}"#);
+        assert_eq!(text.parse::<Range<String>>().unwrap(), range);
+    }
+}
+
+mod test_parse_error {
+    use crate::{ContinuousRange, ParseRangeError, ParseRangeErrorKind, Range};
+    use assert_matches::assert_matches;
+    use std::{error::Error, num::ParseIntError};
+
+    fn parse_continuous(s: &str) -> ParseRangeError<ParseIntError> {
+        s.parse::<ContinuousRange<i32>>().unwrap_err()
+    }
+
+    fn parse_range(s: &str) -> ParseRangeError<ParseIntError> {
+        s.parse::<Range<i32>>().unwrap_err()
+    }
+
+    #[test]
+    pub fn empty() {
+        let error = parse_continuous("  ");
+        assert_eq!(error.position(), 2);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::Empty);
+    }
+
+    #[test]
+    pub fn invalid_value() {
+        let error = parse_continuous("[1.. x)");
+        assert_eq!(error.position(), 5);
+        assert_matches!(error.kind(), ParseRangeErrorKind::InvalidValue(_));
+        assert_eq!(
+            error.to_string(),
+            "invalid value: invalid digit found in string at position 5"
+        );
+        assert!(error.source().is_some());
+
+        let error = parse_continuous("x");
+        assert_eq!(error.position(), 0);
+        assert_matches!(error.kind(), ParseRangeErrorKind::InvalidValue(_));
+    }
+
+    #[test]
+    pub fn missing_closing_bracket() {
+        let error = parse_continuous("[1..5");
+        assert_eq!(error.position(), 5);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingClosingBracket);
+        assert_eq!(error.to_string(), "expected `]` or `)` at position 5");
+        assert!(error.source().is_none());
+
+        assert_eq!(
+            parse_continuous("[").kind(),
+            &ParseRangeErrorKind::MissingClosingBracket
+        );
+    }
+
+    #[test]
+    pub fn missing_separator() {
+        let error = parse_continuous("[1, 5]");
+        assert_eq!(error.position(), 5);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingSeparator);
+    }
+
+    #[test]
+    pub fn missing_closing_brace() {
+        let error = parse_range("{[1..2]; 5");
+        assert_eq!(error.position(), 10);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingClosingBrace);
+    }
+
+    #[test]
+    pub fn composite_component() {
+        let error = parse_range("{[1..2]; [4..x]}");
+        assert_eq!(error.position(), 13);
+        assert_matches!(error.kind(), ParseRangeErrorKind::InvalidValue(_));
+
+        let error = parse_range("{[1..2]; }");
+        assert_eq!(error.position(), 9);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::Empty);
+    }
+
+    #[test]
+    pub fn trailing_characters() {
+        let error = parse_continuous("[1..5) x");
+        assert_eq!(error.position(), 7);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::TrailingCharacters);
+        assert_eq!(
+            error.to_string(),
+            "unexpected characters after the range at position 7"
+        );
+
+        assert_eq!(
+            parse_continuous("[]]").kind(),
+            &ParseRangeErrorKind::TrailingCharacters
+        );
+        assert_eq!(
+            parse_range("{[1..2]} [3..4]").kind(),
+            &ParseRangeErrorKind::TrailingCharacters
+        );
+        assert_eq!(
+            parse_range("[1..2]; 5").kind(),
+            &ParseRangeErrorKind::TrailingCharacters
+        );
+    }
+
+    #[test]
+    pub fn unterminated_quote() {
+        let error = "[\"a..b]".parse::<ContinuousRange<String>>().unwrap_err();
+        assert_eq!(error.position(), 7);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::UnterminatedQuote);
+    }
+}
+
+mod test_display {
+    use crate::{ContinuousRange, Notation, Range};
+
+    fn all(range: &ContinuousRange<i32>) -> [String; 4] {
+        [
+            Notation::Native,
+            Notation::PostgreSql,
+            Notation::Interval,
+            Notation::Rust,
+        ]
+        .map(|notation| range.display().notation(notation).to_string())
+    }
+
+    #[test]
+    pub fn display_is_native() {
+        let range = ContinuousRange::start_exclusive(1, 5);
+        assert_eq!(range.to_string(), "(1..5]");
+        assert_eq!(range.to_string(), format!("{range:?}"));
+        assert_eq!(Range::Continuous(range).to_string(), "(1..5]");
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(all(&ContinuousRange::empty()), ["[]", "empty", "∅", "[]"]);
+    }
+
+    #[test]
+    pub fn single() {
+        assert_eq!(
+            all(&ContinuousRange::single(5)),
+            ["5", "[5,5]", "{5}", "5..=5"]
+        );
+    }
+
+    #[test]
+    pub fn full() {
+        assert_eq!(
+            all(&ContinuousRange::full()),
+            ["(..)", "(,)", "(-∞, +∞)", ".."]
+        );
+    }
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(
+            all(&ContinuousRange::inclusive(1, 5)),
+            ["[1..5]", "[1,5]", "[1, 5]", "1..=5"]
+        );
+        assert_eq!(
+            all(&ContinuousRange::end_exclusive(1, 5)),
+            ["[1..5)", "[1,5)", "[1, 5)", "1..5"]
+        );
+        assert_eq!(
+            all(&ContinuousRange::exclusive(1, 5)),
+            ["(1..5)", "(1,5)", "(1, 5)", "(Excluded(1), Excluded(5))"]
+        );
+        assert_eq!(
+            all(&ContinuousRange::start_exclusive(-5, -1)),
+            [
+                "(-5..-1]",
+                "(-5,-1]",
+                "(-5, -1]",
+                "(Excluded(-5), Included(-1))"
+            ]
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            all(&ContinuousRange::from(1)),
+            ["[1..)", "[1,)", "[1, +∞)", "1.."]
+        );
+        assert_eq!(
+            all(&ContinuousRange::from_exclusive(1)),
+            ["(1..)", "(1,)", "(1, +∞)", "(Excluded(1), Unbounded)"]
+        );
+        assert_eq!(
+            all(&ContinuousRange::to(3)),
+            ["(..3]", "(,3]", "(-∞, 3]", "..=3"]
+        );
+        assert_eq!(
+            all(&ContinuousRange::to_exclusive(3)),
+            ["(..3)", "(,3)", "(-∞, 3)", "..3"]
+        );
+    }
+
+    #[test]
+    pub fn value_format_options() {
+        let range = ContinuousRange::end_exclusive(1.0, 2.5);
+        assert_eq!(format!("{range:.2}"), "[1.00..2.50)");
+        assert_eq!(
+            format!("{:.1}", range.display().notation(Notation::Interval)),
+            "[1.0, 2.5)"
+        );
+    }
+
+    #[test]
+    pub fn postgresql_quoting() {
+        let range = ContinuousRange::inclusive("a b".to_string(), "c\"d".to_string());
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            r#"["a b","c\"d"]"#
+        );
+
+        let range = ContinuousRange::end_exclusive(String::new(), "a,b".to_string());
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            r#"["","a,b")"#
+        );
+    }
+
+    #[test]
+    pub fn native_quoting() {
+        let range = ContinuousRange::inclusive("a..b".to_string(), "c".to_string());
+        assert_eq!(range.to_string(), r#"["a..b"..c]"#);
+
+        let range = ContinuousRange::end_exclusive(String::new(), "say \"hi\"".to_string());
+        assert_eq!(range.to_string(), r#"["".."say \"hi\"")"#);
+
+        let range = ContinuousRange::end_exclusive(-1.5, 2.5);
+        assert_eq!(range.to_string(), "[-1.5..2.5)");
+    }
+
+    #[test]
+    pub fn composite() {
+        let range = Range::composite(vec![
+            Range::to_exclusive(0),
+            Range::continuous(1, 2),
+            Range::single(5),
+        ]);
+
+        assert_eq!(range.to_string(), "{(..0); [1..2]; 5}");
+        assert_eq!(range.to_string(), format!("{range:?}"));
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            "{(,0), [1,2], [5,5]}"
+        );
+        assert_eq!(
+            range.display().notation(Notation::Interval).to_string(),
+            "(-∞, 0) ∪ [1, 2] ∪ {5}"
+        );
+        assert_eq!(
+            range.display().notation(Notation::Rust).to_string(),
+            "[..0, 1..=2, 5..=5]"
+        );
+    }
+
+    #[test]
+    pub fn empty_composite() {
+        let range = Range::<i32>::Composite(vec![]);
+        assert_eq!(range.to_string(), "{}");
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            "{}"
+        );
+        assert_eq!(
+            range.display().notation(Notation::Interval).to_string(),
+            "∅"
+        );
+        assert_eq!(range.display().notation(Notation::Rust).to_string(), "[]");
+    }
+
+    #[test]
+    pub fn postgresql_multirange_skips_empty() {
+        let range = Range::Composite(vec![ContinuousRange::single(1), ContinuousRange::empty()]);
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            "{[1,1]}"
+        );
+    }
+
+    #[test]
+    pub fn round_trip_parse() {
+        let range = Range::composite(vec![
+            Range::to(-10),
+            Range::continuous_start_exclusive(0, 5),
+            Range::single(7),
+        ]);
+        assert_eq!(range.to_string().parse::<Range<i32>>().unwrap(), range);
+    }
+}
+
+mod test_parse_postgresql {
+    use crate::{ContinuousRange, Notation, ParseRangeErrorKind, Range};
+    use assert_matches::assert_matches;
+
+    fn parse(s: &str) -> ContinuousRange<i32> {
+        ContinuousRange::parse_postgresql(s).unwrap()
+    }
+
+    #[test]
+    pub fn ranges() {
+        assert_eq!(parse("[1,5)"), ContinuousRange::end_exclusive(1, 5));
+        assert_eq!(parse("(1,5]"), ContinuousRange::start_exclusive(1, 5));
+        assert_eq!(parse("[1,5]"), ContinuousRange::inclusive(1, 5));
+        assert_eq!(parse("(,3]"), ContinuousRange::to(3));
+        assert_eq!(parse("[1,)"), ContinuousRange::from(1));
+        assert_eq!(parse("(,)"), ContinuousRange::full());
+        assert_eq!(parse("[,]"), ContinuousRange::full());
+        assert_eq!(parse("empty"), ContinuousRange::empty());
+        assert_eq!(parse(" EMPTY "), ContinuousRange::empty());
+        assert_eq!(parse("[5,1]"), ContinuousRange::empty());
+    }
+
+    #[test]
+    pub fn whitespace() {
+        assert_eq!(parse("  [ 1 , 5 )  "), ContinuousRange::end_exclusive(1, 5));
+        assert_eq!(parse("( , 3]"), ContinuousRange::to(3));
+    }
+
+    #[test]
+    pub fn quoted() {
+        assert_eq!(parse(r#"["1","5")"#), ContinuousRange::end_exclusive(1, 5));
+
+        let range = ContinuousRange::<String>::parse_postgresql(r#"["a b","c\"d""e"]"#).unwrap();
+        assert_eq!(
+            range,
+            ContinuousRange::inclusive("a b".to_string(), "c\"d\"e".to_string())
+        );
+
+        let range = ContinuousRange::<String>::parse_postgresql(r#"["",a\,b)"#).unwrap();
+        assert_eq!(
+            range,
+            ContinuousRange::end_exclusive(String::new(), "a,b".to_string())
+        );
+    }
+
+    #[test]
+    pub fn round_trip_display() {
+        let ranges = [
+            ContinuousRange::inclusive("a b".to_string(), "c\"d\\e".to_string()),
+            ContinuousRange::from_exclusive("(,)".to_string()),
+            ContinuousRange::empty(),
+        ];
+
+        for range in ranges {
+            let text = range.display().notation(Notation::PostgreSql).to_string();
+            assert_eq!(ContinuousRange::parse_postgresql(&text).unwrap(), range);
+        }
+    }
+
+    #[test]
+    pub fn multirange() {
+        assert_eq!(
+            Range::<i32>::parse_postgresql("{[1,3), [5,7)}").unwrap(),
+            Range::composite(vec![
+                Range::continuous_end_exclusive(1, 3),
+                Range::continuous_end_exclusive(5, 7)
+            ])
+        );
+        assert_eq!(
+            Range::<i32>::parse_postgresql(" { [5,) ,(,1) , empty } ").unwrap(),
+            Range::composite(vec![Range::from(5), Range::to_exclusive(1)])
+        );
+        assert_eq!(
+            Range::<i32>::parse_postgresql("{}").unwrap(),
+            Range::empty()
+        );
+        assert_eq!(
+            Range::<i32>::parse_postgresql("[1,5)").unwrap(),
+            Range::continuous_end_exclusive(1, 5)
+        );
+
+        let range = Range::composite(vec![Range::to(-1), Range::continuous(1, 2)]);
+        let text = range.display().notation(Notation::PostgreSql).to_string();
+        assert_eq!(Range::parse_postgresql(&text).unwrap(), range);
+    }
+
+    #[test]
+    pub fn inverted_is_empty() {
+        let range = ContinuousRange::Inclusive(5, 1);
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            "empty"
+        );
+        assert_eq!(range.to_string(), "[5..1]");
+
+        let range = Range::Composite(vec![
+            ContinuousRange::Exclusive(3, 3),
+            ContinuousRange::single(1),
+        ]);
+        assert_eq!(
+            range.display().notation(Notation::PostgreSql).to_string(),
+            "{[1,1]}"
+        );
+    }
+
+    #[test]
+    pub fn multirange_single_and_empty() {
+        let range = Range::continuous_end_exclusive(1, 5);
+        let text = range.display().notation(Notation::PostgreSql).to_string();
+        assert_eq!(text, "{[1,5)}");
+        assert_eq!(Range::parse_postgresql(&text).unwrap(), range);
+
+        let range = Range::<i32>::empty();
+        let text = range.display().notation(Notation::PostgreSql).to_string();
+        assert_eq!(text, "{}");
+        assert_eq!(Range::parse_postgresql(&text).unwrap(), range);
+    }
+
+    #[test]
+    pub fn errors() {
+        let error = ContinuousRange::<i32>::parse_postgresql("").unwrap_err();
+        assert_eq!(error.kind(), &ParseRangeErrorKind::Empty);
+
+        let error = ContinuousRange::<i32>::parse_postgresql("1,5)").unwrap_err();
+        assert_eq!(error.position(), 0);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingOpeningBracket);
+
+        let error = ContinuousRange::<i32>::parse_postgresql("[1..5)").unwrap_err();
+        assert_eq!(error.position(), 1);
+        assert_matches!(error.kind(), ParseRangeErrorKind::InvalidValue(_));
+
+        let error = ContinuousRange::<i32>::parse_postgresql("[1,5").unwrap_err();
+        assert_eq!(error.position(), 4);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingClosingBracket);
+
+        let error = ContinuousRange::<i32>::parse_postgresql("[1)").unwrap_err();
+        assert_eq!(error.position(), 2);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingSeparator);
+
+        let error = ContinuousRange::<i32>::parse_postgresql("[\"1,5)").unwrap_err();
+        assert_eq!(error.kind(), &ParseRangeErrorKind::UnterminatedQuote);
+
+        let error = ContinuousRange::<i32>::parse_postgresql("[1,5) x").unwrap_err();
+        assert_eq!(error.position(), 6);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::TrailingCharacters);
+
+        let error = Range::<i32>::parse_postgresql("{[1,3) [5,7)}").unwrap_err();
+        assert_eq!(error.position(), 7);
+        assert_eq!(error.kind(), &ParseRangeErrorKind::MissingClosingBrace);
+    }
+}
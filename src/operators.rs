@@ -0,0 +1,146 @@
+//! Implementation of the [`std::ops`] operators on ranges, mirroring the set operators of
+//! [`std::collections::BTreeSet`]:
+//!
+//! - `a | b` is the union
+//! - `a & b` is the intersection
+//! - `a - b` is the difference
+//! - `a ^ b` is the symmetric difference
+//! - `!a` is the complement
+//!
+//! Operations on [`ContinuousRange`] produce a [`Range`] except for the intersection that is always continuous.
+
+use std::{
+    fmt,
+    ops::{
+        Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+    },
+};
+
+use crate::{ContinuousRange, Range};
+
+/// Implement a binary operator for all the combinations of owned and borrowed operands
+macro_rules! binary_op {
+    (impl $imp:ident, $method:ident for $t:ident -> $output:ty, |$a:ident, $b:ident| $body:expr) => {
+        impl<Idx: PartialOrd + Clone + fmt::Debug> $imp<$t<Idx>> for $t<Idx> {
+            type Output = $output;
+
+            fn $method(self, other: $t<Idx>) -> $output {
+                let ($a, $b) = (&self, &other);
+                $body
+            }
+        }
+
+        impl<'a, Idx: PartialOrd + Clone + fmt::Debug> $imp<&'a $t<Idx>> for $t<Idx> {
+            type Output = $output;
+
+            fn $method(self, other: &'a $t<Idx>) -> $output {
+                let ($a, $b) = (&self, other);
+                $body
+            }
+        }
+
+        impl<'a, Idx: PartialOrd + Clone + fmt::Debug> $imp<$t<Idx>> for &'a $t<Idx> {
+            type Output = $output;
+
+            fn $method(self, other: $t<Idx>) -> $output {
+                let ($a, $b) = (self, &other);
+                $body
+            }
+        }
+
+        impl<'a, 'b, Idx: PartialOrd + Clone + fmt::Debug> $imp<&'b $t<Idx>> for &'a $t<Idx> {
+            type Output = $output;
+
+            fn $method(self, other: &'b $t<Idx>) -> $output {
+                let ($a, $b) = (self, other);
+                $body
+            }
+        }
+    };
+}
+
+/// Implement an assignment operator for owned and borrowed right operands
+macro_rules! assign_op {
+    (impl $imp:ident, $method:ident for $t:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<Idx: PartialOrd + Clone + fmt::Debug> $imp<$t<Idx>> for $t<Idx> {
+            fn $method(&mut self, other: $t<Idx>) {
+                let result = {
+                    let ($a, $b) = (&*self, &other);
+                    $body
+                };
+                *self = result;
+            }
+        }
+
+        impl<'a, Idx: PartialOrd + Clone + fmt::Debug> $imp<&'a $t<Idx>> for $t<Idx> {
+            fn $method(&mut self, other: &'a $t<Idx>) {
+                let result = {
+                    let ($a, $b) = (&*self, other);
+                    $body
+                };
+                *self = result;
+            }
+        }
+    };
+}
+
+/// Implement an unary operator for owned and borrowed operands
+macro_rules! unary_op {
+    (impl $imp:ident, $method:ident for $t:ident -> $output:ty, |$a:ident| $body:expr) => {
+        impl<Idx: PartialOrd + Clone + fmt::Debug> $imp for $t<Idx> {
+            type Output = $output;
+
+            fn $method(self) -> $output {
+                let $a = &self;
+                $body
+            }
+        }
+
+        impl<'a, Idx: PartialOrd + Clone + fmt::Debug> $imp for &'a $t<Idx> {
+            type Output = $output;
+
+            fn $method(self) -> $output {
+                let $a = self;
+                $body
+            }
+        }
+    };
+}
+
+binary_op!(impl BitOr, bitor for Range -> Range<Idx>, |a, b| a.union(b));
+binary_op!(impl BitAnd, bitand for Range -> Range<Idx>, |a, b| a.intersection(b));
+binary_op!(impl Sub, sub for Range -> Range<Idx>, |a, b| a.difference(b));
+binary_op!(impl BitXor, bitxor for Range -> Range<Idx>, |a, b| a.symmetric_difference(b));
+unary_op!(impl Not, not for Range -> Range<Idx>, |a| a.complement());
+
+assign_op!(impl BitOrAssign, bitor_assign for Range, |a, b| a.union(b));
+assign_op!(impl BitAndAssign, bitand_assign for Range, |a, b| a.intersection(b));
+assign_op!(impl SubAssign, sub_assign for Range, |a, b| a.difference(b));
+assign_op!(impl BitXorAssign, bitxor_assign for Range, |a, b| a.symmetric_difference(b));
+
+impl<Idx: PartialOrd + Clone + fmt::Debug> Add<&Range<Idx>> for Range<Idx> {
+    type Output = Range<Idx>;
+
+    fn add(self, other: &Range<Idx>) -> Range<Idx> {
+        self.union(other)
+    }
+}
+
+binary_op!(impl BitOr, bitor for ContinuousRange -> Range<Idx>, |a, b| {
+    Range::Continuous(a.clone()).union(&Range::Continuous(b.clone()))
+});
+binary_op!(impl BitAnd, bitand for ContinuousRange -> ContinuousRange<Idx>, |a, b| {
+    a.intersection(b)
+});
+binary_op!(impl Sub, sub for ContinuousRange -> Range<Idx>, |a, b| {
+    Range::Continuous(a.clone()).difference(&Range::Continuous(b.clone()))
+});
+binary_op!(impl BitXor, bitxor for ContinuousRange -> Range<Idx>, |a, b| {
+    a.symmetric_difference(b)
+});
+unary_op!(impl Not, not for ContinuousRange -> Range<Idx>, |a| {
+    Range::Continuous(a.clone()).complement()
+});
+
+// The other operations can't be assigned as their result might not be continuous
+assign_op!(impl BitAndAssign, bitand_assign for ContinuousRange, |a, b| a.intersection(b));
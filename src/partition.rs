@@ -0,0 +1,126 @@
+use std::{cmp::Ordering, collections::BTreeSet, iter::FromIterator, ops::Bound};
+
+use crate::{bounds::reverse_bound, coverage::cmp_positions, ContinuousRange, Range};
+
+/// The decomposition of a collection of ranges into disjoint atoms, each with the indices of the input ranges that
+/// contain it.
+///
+/// Atoms are the largest continuous ranges over which the set of containing inputs doesn't change, they are sorted
+/// and only cover the values contained in at least one input.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, Partition, Range};
+///
+/// let rules = vec![
+///     Range::continuous_end_exclusive(0, 10),
+///     Range::continuous_end_exclusive(5, 15),
+/// ];
+/// let partition: Partition<_> = rules.into_iter().collect();
+///
+/// assert_eq!(
+///     partition.atoms(),
+///     &[
+///         (ContinuousRange::end_exclusive(0, 5), vec![0]),
+///         (ContinuousRange::end_exclusive(5, 10), vec![0, 1]),
+///         (ContinuousRange::end_exclusive(10, 15), vec![1]),
+///     ]
+/// );
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Partition<Idx> {
+    atoms: Vec<(ContinuousRange<Idx>, Vec<usize>)>,
+}
+
+impl<Idx: PartialOrd + Clone> Partition<Idx> {
+    /// Partition `ranges`, the indices of the atoms being the positions of the ranges in the iterator
+    ///
+    /// # Panics
+    ///
+    /// Panics if some bounds can't be compared, like a `NaN` float.
+    pub fn new(ranges: impl IntoIterator<Item = Range<Idx>>) -> Self {
+        // Positions where the membership can change, as the start bound of the atom following them, with the input
+        // starting or ending there. Components of the same input may overlap so each input counts its open components.
+        let mut cuts: Vec<(Bound<Idx>, usize, bool)> = Vec::new();
+        let mut inputs = 0;
+        for (index, range) in ranges.into_iter().enumerate() {
+            inputs += 1;
+            for component in range.components() {
+                let Some((start, end)) = component.range_bounds().filter(|_| !component.is_empty())
+                else {
+                    continue;
+                };
+
+                cuts.push((start.cloned(), index, true));
+                if end != Bound::Unbounded {
+                    cuts.push((reverse_bound(end).cloned(), index, false));
+                }
+            }
+        }
+        cuts.sort_by(|(a, _, _), (b, _, _)| cmp_positions(a, b));
+
+        let mut atoms = Vec::new();
+        let mut open_components = vec![0usize; inputs];
+        let mut members = BTreeSet::new();
+        let mut atom_start: Option<Bound<Idx>> = None;
+        let mut cuts = cuts.into_iter().peekable();
+        while let Some((position, index, opened)) = cuts.next() {
+            let mut changes = vec![(index, opened)];
+            while let Some((_, index, opened)) =
+                cuts.next_if(|(next, _, _)| cmp_positions(&position, next) == Ordering::Equal)
+            {
+                changes.push((index, opened));
+            }
+
+            let mut next_members = members.clone();
+            for (index, opened) in changes {
+                if opened {
+                    open_components[index] += 1;
+                    next_members.insert(index);
+                } else {
+                    open_components[index] -= 1;
+                    if open_components[index] == 0 {
+                        next_members.remove(&index);
+                    }
+                }
+            }
+            if next_members == members {
+                continue;
+            }
+
+            if let Some(start) = atom_start.take() {
+                let end = reverse_bound(position.as_ref());
+                let atom = ContinuousRange::from_bounds((start.as_ref(), end));
+                atoms.push((atom, members.iter().copied().collect()));
+            }
+            if !next_members.is_empty() {
+                atom_start = Some(position);
+            }
+            members = next_members;
+        }
+        if let Some(start) = atom_start {
+            let atom = ContinuousRange::from_bounds((start.as_ref(), Bound::Unbounded));
+            atoms.push((atom, members.into_iter().collect()));
+        }
+
+        Self { atoms }
+    }
+
+    /// The atoms with the sorted indices of the ranges containing them, in order
+    #[must_use]
+    pub fn atoms(&self) -> &[(ContinuousRange<Idx>, Vec<usize>)] {
+        &self.atoms
+    }
+
+    /// Take the atoms with the sorted indices of the ranges containing them, in order
+    #[must_use]
+    pub fn into_atoms(self) -> Vec<(ContinuousRange<Idx>, Vec<usize>)> {
+        self.atoms
+    }
+}
+
+impl<Idx: PartialOrd + Clone> FromIterator<Range<Idx>> for Partition<Idx> {
+    fn from_iter<T: IntoIterator<Item = Range<Idx>>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
@@ -0,0 +1,109 @@
+mod test_atoms {
+    use crate::{ContinuousRange, Partition, Range};
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Partition::<i32>::new(vec![]).atoms(), &[]);
+        assert_eq!(
+            Partition::new(vec![Range::<i32>::empty(), Range::empty()]).atoms(),
+            &[]
+        );
+    }
+
+    #[test]
+    pub fn disjoint() {
+        let partition = Partition::new(vec![
+            Range::continuous(5, 8),
+            Range::continuous_end_exclusive(1, 3),
+        ]);
+        assert_eq!(
+            partition.into_atoms(),
+            vec![
+                (ContinuousRange::end_exclusive(1, 3), vec![1]),
+                (ContinuousRange::inclusive(5, 8), vec![0]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn shared_inclusive_bound() {
+        let partition = Partition::new(vec![Range::continuous(1, 5), Range::continuous(5, 8)]);
+        assert_eq!(
+            partition.into_atoms(),
+            vec![
+                (ContinuousRange::end_exclusive(1, 5), vec![0]),
+                (ContinuousRange::single(5), vec![0, 1]),
+                (ContinuousRange::start_exclusive(5, 8), vec![1]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn adjacent_inputs_stay_apart() {
+        let partition = Partition::new(vec![
+            Range::continuous_end_exclusive(1, 5),
+            Range::continuous(5, 8),
+        ]);
+        assert_eq!(
+            partition.into_atoms(),
+            vec![
+                (ContinuousRange::end_exclusive(1, 5), vec![0]),
+                (ContinuousRange::inclusive(5, 8), vec![1]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn same_membership_is_one_atom() {
+        let partition = Partition::new(vec![
+            Range::Composite(vec![
+                ContinuousRange::end_exclusive(1, 5),
+                ContinuousRange::inclusive(3, 8),
+            ]),
+            Range::continuous(0, 10),
+        ]);
+        assert_eq!(
+            partition.into_atoms(),
+            vec![
+                (ContinuousRange::end_exclusive(0, 1), vec![1]),
+                (ContinuousRange::inclusive(1, 8), vec![0, 1]),
+                (ContinuousRange::start_exclusive(8, 10), vec![1]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn composite_inputs() {
+        let partition: Partition<_> = vec![
+            Range::composite(vec![Range::continuous(0, 2), Range::continuous(6, 8)]),
+            Range::continuous(1, 7),
+            Range::from(7),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            partition.into_atoms(),
+            vec![
+                (ContinuousRange::end_exclusive(0, 1), vec![0]),
+                (ContinuousRange::inclusive(1, 2), vec![0, 1]),
+                (ContinuousRange::exclusive(2, 6), vec![1]),
+                (ContinuousRange::end_exclusive(6, 7), vec![0, 1]),
+                (ContinuousRange::single(7), vec![0, 1, 2]),
+                (ContinuousRange::start_exclusive(7, 8), vec![0, 2]),
+                (ContinuousRange::from_exclusive(8), vec![2]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let partition = Partition::new(vec![Range::full(), Range::to_exclusive(0)]);
+        assert_eq!(
+            partition.into_atoms(),
+            vec![
+                (ContinuousRange::to_exclusive(0), vec![0, 1]),
+                (ContinuousRange::from(0), vec![0]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! PostgreSQL binary format support, enabled by the `postgres` feature.
+//!
+//! [`ContinuousRange`] maps to range types like `int4range` or `tstzrange` and [`Range`] to multirange types like
+//! `int4multirange` or `tstzmultirange`, in both cases the values are converted using their own [`ToSql`] and
+//! [`FromSql`] implementations.
+//!
+//! A range is sent as a flags byte followed, for each finite bound, by the length of the value as a big-endian `i32`
+//! and the value itself. A multirange is the number of ranges as an `i32` followed by each range prefixed by its
+//! length.
+
+use std::{convert::TryFrom, error::Error, fmt, ops::Bound};
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+
+use crate::{ContinuousRange, Range};
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// Write `f` output prefixed by its length
+fn write_length_prefixed(
+    out: &mut BytesMut,
+    f: impl FnOnce(&mut BytesMut) -> Result<(), BoxError>,
+) -> Result<(), BoxError> {
+    let base = out.len();
+    out.put_i32(0);
+    f(out)?;
+    let len = i32::try_from(out.len() - base - 4)?;
+    out[base..base + 4].copy_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+fn write_bound<T: ToSql>(bound: Bound<&T>, ty: &Type, out: &mut BytesMut) -> Result<(), BoxError> {
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) => {
+            write_length_prefixed(out, |out| match value.to_sql(ty, out)? {
+                IsNull::No => Ok(()),
+                IsNull::Yes => Err("range bounds can't be NULL".into()),
+            })
+        }
+        Bound::Unbounded => Ok(()),
+    }
+}
+
+fn write_range<T: ToSql + PartialOrd>(
+    range: &ContinuousRange<T>,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<(), BoxError> {
+    // PostgreSQL rejects inverted bounds, ranges that are empty because of them are sent as empty
+    let Some((start, end)) = range.range_bounds().filter(|_| !range.is_empty()) else {
+        out.put_u8(RANGE_EMPTY);
+        return Ok(());
+    };
+
+    let start_flags = match start {
+        Bound::Included(_) => RANGE_LB_INC,
+        Bound::Excluded(_) => 0,
+        Bound::Unbounded => RANGE_LB_INF,
+    };
+    let end_flags = match end {
+        Bound::Included(_) => RANGE_UB_INC,
+        Bound::Excluded(_) => 0,
+        Bound::Unbounded => RANGE_UB_INF,
+    };
+
+    out.put_u8(start_flags | end_flags);
+    write_bound(start, ty, out)?;
+    write_bound(end, ty, out)
+}
+
+fn read_i32(buf: &mut &[u8]) -> Result<i32, BoxError> {
+    if buf.len() < 4 {
+        return Err("invalid message size".into());
+    }
+    let (bytes, rest) = buf.split_at(4);
+    *buf = rest;
+    Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Read a length-prefixed value
+fn read_length_prefixed<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], BoxError> {
+    let len = usize::try_from(read_i32(buf)?).map_err(|_| "unexpected NULL value")?;
+    if buf.len() < len {
+        return Err("invalid message size".into());
+    }
+    let (value, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(value)
+}
+
+fn read_bound<'a, T: FromSql<'a>>(
+    buf: &mut &'a [u8],
+    ty: &Type,
+    flags: u8,
+    inclusive_flag: u8,
+    infinite_flag: u8,
+) -> Result<Bound<T>, BoxError> {
+    if flags & infinite_flag != 0 {
+        return Ok(Bound::Unbounded);
+    }
+
+    let value = T::from_sql(ty, read_length_prefixed(buf)?)?;
+    Ok(if flags & inclusive_flag != 0 {
+        Bound::Included(value)
+    } else {
+        Bound::Excluded(value)
+    })
+}
+
+fn read_range<'a, T>(mut buf: &'a [u8], ty: &Type) -> Result<ContinuousRange<T>, BoxError>
+where
+    T: FromSql<'a> + PartialOrd + Clone,
+{
+    let (&flags, rest) = buf.split_first().ok_or("invalid message size")?;
+    buf = rest;
+
+    let range = if flags & RANGE_EMPTY == 0 {
+        let start = read_bound(&mut buf, ty, flags, RANGE_LB_INC, RANGE_LB_INF)?;
+        let end = read_bound(&mut buf, ty, flags, RANGE_UB_INC, RANGE_UB_INF)?;
+        ContinuousRange::from_bounds((start.as_ref(), end.as_ref()))
+    } else {
+        ContinuousRange::Empty
+    };
+
+    if buf.is_empty() {
+        Ok(range)
+    } else {
+        Err("invalid message size".into())
+    }
+}
+
+impl<T: ToSql + PartialOrd> ToSql for ContinuousRange<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        let Kind::Range(element_type) = ty.kind() else {
+            return Err(format!("unexpected type {ty}").into());
+        };
+
+        write_range(self, element_type, out)?;
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Range(element_type) if T::accepts(element_type))
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a, T> FromSql<'a> for ContinuousRange<T>
+where
+    T: FromSql<'a> + PartialOrd + Clone,
+{
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let Kind::Range(element_type) = ty.kind() else {
+            return Err(format!("unexpected type {ty}").into());
+        };
+
+        read_range(raw, element_type)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Range(element_type) if T::accepts(element_type))
+    }
+}
+
+impl<T: ToSql + PartialOrd + Clone> ToSql for Range<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        let Kind::Multirange(element_type) = ty.kind() else {
+            return Err(format!("unexpected type {ty}").into());
+        };
+
+        // PostgreSQL expects the ranges of a multirange to be sorted, non-empty and neither overlapping nor adjacent
+        let simplified = self.simplify();
+        let components = match &simplified {
+            Range::Continuous(r) => std::slice::from_ref(r),
+            Range::Composite(v) => v.as_slice(),
+        };
+        let non_empty: Vec<_> = components.iter().filter(|r| !r.is_empty()).collect();
+
+        out.put_i32(i32::try_from(non_empty.len())?);
+        for range in non_empty {
+            write_length_prefixed(out, |out| write_range(range, element_type, out))?;
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Multirange(element_type) if T::accepts(element_type))
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a, T> FromSql<'a> for Range<T>
+where
+    T: FromSql<'a> + PartialOrd + Clone + fmt::Debug,
+{
+    fn from_sql(ty: &Type, mut raw: &'a [u8]) -> Result<Self, BoxError> {
+        let Kind::Multirange(element_type) = ty.kind() else {
+            return Err(format!("unexpected type {ty}").into());
+        };
+
+        let count = usize::try_from(read_i32(&mut raw)?)?;
+        let mut ranges = Vec::with_capacity(count.min(raw.len()));
+        for _ in 0..count {
+            let range = read_range(read_length_prefixed(&mut raw)?, element_type)?;
+            ranges.push(Range::Continuous(range));
+        }
+
+        if raw.is_empty() {
+            Ok(Range::composite(ranges))
+        } else {
+            Err("invalid message size".into())
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Multirange(element_type) if T::accepts(element_type))
+    }
+}
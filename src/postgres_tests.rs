@@ -0,0 +1,245 @@
+use bytes::BytesMut;
+use postgres_types::{FromSql, ToSql, Type};
+
+fn to_sql<T: ToSql>(value: &T, ty: &Type) -> Vec<u8> {
+    let mut out = BytesMut::new();
+    value.to_sql_checked(ty, &mut out).unwrap();
+    out.to_vec()
+}
+
+fn from_sql<'a, T: FromSql<'a>>(raw: &'a [u8], ty: &Type) -> T {
+    assert!(T::accepts(ty));
+    T::from_sql(ty, raw).unwrap()
+}
+
+mod test_range {
+    use super::{from_sql, to_sql};
+    use crate::ContinuousRange;
+    use postgres_types::{FromSql, ToSql, Type};
+    use std::time::{Duration, SystemTime};
+
+    const INT4_END_EXCLUSIVE: &[u8] = &[
+        0x02, // lower bound inclusive
+        0, 0, 0, 4, 0, 0, 0, 1, // 1
+        0, 0, 0, 4, 0, 0, 0, 5, // 5
+    ];
+
+    #[test]
+    pub fn end_exclusive() {
+        let range = ContinuousRange::end_exclusive(1, 5);
+        assert_eq!(to_sql(&range, &Type::INT4_RANGE), INT4_END_EXCLUSIVE);
+        assert_eq!(
+            from_sql::<ContinuousRange<i32>>(INT4_END_EXCLUSIVE, &Type::INT4_RANGE),
+            range
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        let range = ContinuousRange::<i32>::empty();
+        assert_eq!(to_sql(&range, &Type::INT4_RANGE), [0x01]);
+        assert_eq!(
+            from_sql::<ContinuousRange<i32>>(&[0x01], &Type::INT4_RANGE),
+            range
+        );
+    }
+
+    #[test]
+    pub fn inverted_is_empty() {
+        assert_eq!(
+            to_sql(&ContinuousRange::Inclusive(5, 1), &Type::INT4_RANGE),
+            [0x01]
+        );
+        assert_eq!(
+            to_sql(&ContinuousRange::EndExclusive(3, 3), &Type::INT4_RANGE),
+            [0x01]
+        );
+    }
+
+    #[test]
+    pub fn full() {
+        let range = ContinuousRange::<i32>::full();
+        assert_eq!(to_sql(&range, &Type::INT4_RANGE), [0x18]);
+        assert_eq!(
+            from_sql::<ContinuousRange<i32>>(&[0x18], &Type::INT4_RANGE),
+            range
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let to: &[u8] = &[0x0C, 0, 0, 0, 4, 0, 0, 0, 3];
+        assert_eq!(to_sql(&ContinuousRange::to(3), &Type::INT4_RANGE), to);
+        assert_eq!(
+            from_sql::<ContinuousRange<i32>>(to, &Type::INT4_RANGE),
+            ContinuousRange::to(3)
+        );
+
+        let from_exclusive: &[u8] = &[0x10, 0, 0, 0, 4, 0, 0, 0, 3];
+        assert_eq!(
+            to_sql(&ContinuousRange::from_exclusive(3), &Type::INT4_RANGE),
+            from_exclusive
+        );
+        assert_eq!(
+            from_sql::<ContinuousRange<i32>>(from_exclusive, &Type::INT4_RANGE),
+            ContinuousRange::from_exclusive(3)
+        );
+    }
+
+    #[test]
+    pub fn single() {
+        let single: &[u8] = &[
+            0x06, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7,
+        ];
+        assert_eq!(
+            to_sql(&ContinuousRange::single(7i64), &Type::INT8_RANGE),
+            single
+        );
+        assert_eq!(
+            from_sql::<ContinuousRange<i64>>(single, &Type::INT8_RANGE),
+            ContinuousRange::inclusive(7, 7)
+        );
+    }
+
+    #[test]
+    pub fn timestamptz() {
+        let raw: &[u8] = &[
+            0x02, // lower bound inclusive
+            0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, // 2000-01-01
+            0, 0, 0, 8, 0, 0, 0, 0x14, 0x1D, 0xD7, 0x60, 0x00, // 2000-01-02
+        ];
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(946_684_800);
+        let range = ContinuousRange::end_exclusive(start, start + Duration::from_secs(86_400));
+
+        assert_eq!(to_sql(&range, &Type::TSTZ_RANGE), raw);
+        assert_eq!(
+            from_sql::<ContinuousRange<_>>(raw, &Type::TSTZ_RANGE),
+            range
+        );
+    }
+
+    #[test]
+    pub fn accepts() {
+        assert_eq!(
+            <ContinuousRange<i32> as ToSql>::accepts(&Type::INT4_RANGE),
+            true
+        );
+        assert_eq!(
+            <ContinuousRange<i32> as ToSql>::accepts(&Type::INT8_RANGE),
+            false
+        );
+        assert_eq!(<ContinuousRange<i32> as ToSql>::accepts(&Type::INT4), false);
+        assert_eq!(
+            <ContinuousRange<i32> as FromSql>::accepts(&Type::INT4MULTI_RANGE),
+            false
+        );
+    }
+
+    #[test]
+    pub fn invalid() {
+        let ty = &Type::INT4_RANGE;
+        assert!(ContinuousRange::<i32>::from_sql(ty, &[]).is_err());
+        assert!(ContinuousRange::<i32>::from_sql(ty, &[0x01, 0x00]).is_err());
+        assert!(ContinuousRange::<i32>::from_sql(ty, &INT4_END_EXCLUSIVE[..10]).is_err());
+        assert!(ContinuousRange::<i32>::from_sql(ty, &[0x08]).is_err());
+        assert!(ContinuousRange::<i32>::from_sql(ty, &[0x18, 0xFF]).is_err());
+    }
+}
+
+mod test_multirange {
+    use super::{from_sql, to_sql};
+    use crate::{ContinuousRange, Range};
+    use postgres_types::{FromSql, ToSql, Type};
+
+    const INT4_TWO_RANGES: &[u8] = &[
+        0, 0, 0, 2, // 2 ranges
+        0, 0, 0, 17, 0x02, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 3, // [1,3)
+        0, 0, 0, 9, 0x12, 0, 0, 0, 4, 0, 0, 0, 5, // [5,)
+    ];
+
+    #[test]
+    pub fn composite() {
+        let range = Range::composite(vec![Range::continuous_end_exclusive(1, 3), Range::from(5)]);
+        assert_eq!(to_sql(&range, &Type::INT4MULTI_RANGE), INT4_TWO_RANGES);
+        assert_eq!(
+            from_sql::<Range<i32>>(INT4_TWO_RANGES, &Type::INT4MULTI_RANGE),
+            range
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(
+            to_sql(&Range::<i32>::empty(), &Type::INT4MULTI_RANGE),
+            [0, 0, 0, 0]
+        );
+        assert_eq!(
+            to_sql(
+                &Range::Composite(vec![ContinuousRange::<i32>::Empty]),
+                &Type::INT4MULTI_RANGE
+            ),
+            [0, 0, 0, 0]
+        );
+        assert_eq!(
+            from_sql::<Range<i32>>(&[0, 0, 0, 0], &Type::INT4MULTI_RANGE),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn continuous() {
+        let raw: &[u8] = &[0, 0, 0, 1, 0, 0, 0, 1, 0x18];
+        assert_eq!(to_sql(&Range::<i32>::full(), &Type::INT4MULTI_RANGE), raw);
+        assert_eq!(
+            from_sql::<Range<i32>>(raw, &Type::INT4MULTI_RANGE),
+            Range::full()
+        );
+    }
+
+    #[test]
+    pub fn sent_simplified() {
+        let range = Range::Composite(vec![
+            ContinuousRange::from(3),
+            ContinuousRange::Empty,
+            ContinuousRange::end_exclusive(1, 3),
+            ContinuousRange::inclusive(2, 4),
+        ]);
+        let raw: &[u8] = &[
+            0, 0, 0, 1, // 1 range
+            0, 0, 0, 9, 0x12, 0, 0, 0, 4, 0, 0, 0, 1, // [1,)
+        ];
+        assert_eq!(to_sql(&range, &Type::INT4MULTI_RANGE), raw);
+    }
+
+    #[test]
+    pub fn result_is_simplified() {
+        let raw: &[u8] = &[
+            0, 0, 0, 2, // 2 ranges
+            0, 0, 0, 9, 0x12, 0, 0, 0, 4, 0, 0, 0, 5, // [5,)
+            0, 0, 0, 17, 0x02, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 5, // [1,5)
+        ];
+        assert_eq!(
+            from_sql::<Range<i32>>(raw, &Type::INT4MULTI_RANGE),
+            Range::from(1)
+        );
+    }
+
+    #[test]
+    pub fn accepts() {
+        assert_eq!(<Range<i32> as ToSql>::accepts(&Type::INT4MULTI_RANGE), true);
+        assert_eq!(<Range<i32> as ToSql>::accepts(&Type::INT4_RANGE), false);
+        assert_eq!(
+            <Range<i64> as FromSql>::accepts(&Type::INT8MULTI_RANGE),
+            true
+        );
+    }
+
+    #[test]
+    pub fn invalid() {
+        let ty = &Type::INT4MULTI_RANGE;
+        assert!(Range::<i32>::from_sql(ty, &[0, 0, 0]).is_err());
+        assert!(Range::<i32>::from_sql(ty, &[0, 0, 0, 1]).is_err());
+        assert!(Range::<i32>::from_sql(ty, &INT4_TWO_RANGES[..20]).is_err());
+        assert!(Range::<i32>::from_sql(ty, &[0, 0, 0, 0, 0]).is_err());
+    }
+}
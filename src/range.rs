@@ -2,12 +2,24 @@
 
 use std::{
     borrow::Borrow,
+    cmp::Ordering,
     fmt,
-    ops::{self, Add, Bound, Sub},
+    iter::Sum,
+    ops::{self, Bound},
+    str::FromStr,
 };
 
-use crate::{ContinuousRange, RangesRelation};
+use crate::{
+    bounds::{max_end, min_start, partial_cmp_bounds, reverse_bound, BoundSide},
+    entries::range_from_disjoint,
+    notation::parse_postgresql_multirange,
+    ContinuousRange, Discrete, ParseRangeError, RangeDisplay, RangeIter, RangesRelation,
+};
 
+/// A range made of any number of [`ContinuousRange`], possibly with holes between them
+///
+/// Equality compares how ranges are written: for a [`Discrete`] type `[1..5]` and `[1..6)` contain the same values
+/// but aren't equal. Compare them with [`Range::discrete_eq`] or after [`Range::canonicalize`].
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Hash, PartialEq)]
 pub enum Range<Idx> {
@@ -120,53 +132,86 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         Self::Continuous(ContinuousRange::full())
     }
 
+    /// Create a new continuous range from any [`ops::RangeBounds`] implementation, like the std range types or a tuple
+    /// of [`Bound`]
+    #[must_use]
+    pub fn from_range_bounds(bounds: impl ops::RangeBounds<Idx>) -> Self {
+        Self::Continuous(ContinuousRange::from_range_bounds(bounds))
+    }
+
+    /// A range composed of all the values of the specified ranges
+    ///
+    /// The result is simplified: components are sorted and overlapping or adjacent ones are merged.
     #[must_use]
     pub fn composite(items: impl IntoIterator<Item = Range<Idx>>) -> Range<Idx>
     where
-        Idx: PartialOrd,
+        Idx: PartialOrd + fmt::Debug,
     {
-        let mut iter = items.into_iter();
-
-        match iter.size_hint() {
-            (_, Some(0)) => Self::empty(),
-            (1, Some(1)) => iter.next().unwrap(),
-            _ => {
-                let mut new_items = vec![];
-
-                for item in iter {
-                    if item.is_empty() {
-                        continue;
-                    }
+        let mut new_items = vec![];
 
-                    if item.is_full() {
-                        return item;
-                    }
-
-                    match item {
-                        Self::Composite(v) => {
-                            new_items.extend(v);
-                        }
-                        Self::Continuous(r) => new_items.push(r),
-                    }
-                }
-
-                match new_items.len() {
-                    0 => Self::empty(),
-                    1 => Self::Continuous(new_items.into_iter().next().unwrap()),
-                    _ => Self::Composite(new_items),
+        for item in items {
+            match item {
+                Self::Composite(v) => {
+                    new_items.extend(v);
                 }
+                Self::Continuous(r) => new_items.push(r),
             }
         }
+
+        let mut range = Self::Composite(new_items);
+        range.simplify_mut();
+        range
+    }
+
+    /// The continuous ranges composing this range
+    ///
+    /// A [`Range::Continuous`] is composed of a single range.
+    #[must_use]
+    pub fn components(&self) -> &[ContinuousRange<Idx>] {
+        match self {
+            Self::Continuous(r) => std::slice::from_ref(r),
+            Self::Composite(v) => v,
+        }
     }
 
+    /// Get the bounds of the range or [None] if empty
+    ///
+    /// For composite ranges these are the bounds of the [hull][Range::hull] of the range.
     #[must_use]
     pub fn range_bounds(&self) -> Option<(Bound<&Idx>, Bound<&Idx>)> {
         match self {
             Self::Continuous(r) => r.range_bounds(),
-            Self::Composite(_) => None, // TODO: custom implementation of bounds
+            Self::Composite(v) => v
+                .iter()
+                .filter(|item| !item.is_empty())
+                .filter_map(ContinuousRange::range_bounds)
+                .reduce(|(hull_start, hull_end), (start, end)| {
+                    (min_start(hull_start, start), max_end(hull_end, end))
+                }),
         }
     }
 
+    /// Smallest continuous range covering all the values of this range
+    ///
+    /// `{[1..3); [5..)}` has `[1..)` as hull.
+    #[must_use]
+    pub fn hull(&self) -> ContinuousRange<Idx> {
+        self.range_bounds()
+            .map_or(ContinuousRange::Empty, ContinuousRange::from_bounds)
+    }
+
+    /// Start bound of the range or [None] if empty
+    #[must_use]
+    pub fn start(&self) -> Option<Bound<&Idx>> {
+        self.range_bounds().map(|(start, _)| start)
+    }
+
+    /// End bound of the range or [None] if empty
+    #[must_use]
+    pub fn end(&self) -> Option<Bound<&Idx>> {
+        self.range_bounds().map(|(_, end)| end)
+    }
+
     #[must_use]
     pub fn contains(&self, value: impl Borrow<Idx>) -> bool
     where
@@ -181,13 +226,15 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         }
     }
 
+    /// Union of two ranges, containing the values present in either of them
+    ///
+    /// Bounds are compared as is, so for a [`Discrete`] type `[1..3] | [4..6]` stays `{[1..3]; [4..6]}` even if no
+    /// value exists between the two components. Use [`Range::discrete_union`] to merge them.
     #[must_use]
     pub fn union(&self, other: &Range<Idx>) -> Range<Idx>
     where
-        Idx: PartialOrd,
+        Idx: PartialOrd + fmt::Debug,
     {
-        // TODO: Quite a few cases can be optimized, specialized, ...
-        // TODO: Also maybe the ranges should be kept sorted in composite
         match (self, other) {
             (Self::Continuous(ContinuousRange::Empty), r)
             | (r, Self::Continuous(ContinuousRange::Empty)) => r.clone(),
@@ -197,44 +244,176 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         }
     }
 
+    /// Intersection of two ranges, containing the values present in both of them
     #[must_use]
-    pub fn intersection(self, _other: &Range<Idx>) -> Range<Idx> {
-        todo!()
+    pub fn intersection(&self, other: &Range<Idx>) -> Range<Idx>
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        match (self, other) {
+            (Self::Continuous(ContinuousRange::Full), r)
+            | (r, Self::Continuous(ContinuousRange::Full)) => r.clone(),
+            (Self::Continuous(r1), Self::Continuous(r2)) => Self::Continuous(r1.intersection(r2)),
+            (r1, r2) => {
+                let mut items = vec![];
+                for item1 in r1.components() {
+                    for item2 in r2.components() {
+                        items.push(Self::Continuous(item1.intersection(item2)));
+                    }
+                }
+                Range::composite(items)
+            }
+        }
     }
 
+    /// Difference of two ranges, containing the values of this range that aren't in `other`
+    ///
+    /// Removing a range from the middle of another one produces a [`Range::Composite`]:
+    /// `[0..10] - [3..5] = {[0..3); (5..10]}`
     #[must_use]
-    pub fn difference(self, _other: &Range<Idx>) -> Range<Idx> {
-        todo!()
+    pub fn difference(&self, other: &Range<Idx>) -> Range<Idx>
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        let mut items = self.components().to_vec();
+        for removed in other.components() {
+            items = items
+                .iter()
+                .flat_map(|item| continuous_difference(item, removed))
+                .collect();
+        }
+
+        Range::composite(items.into_iter().map(Range::Continuous))
     }
 
+    /// Symmetric difference of two ranges, containing the values present in exactly one of them
+    ///
+    /// `[0..10] ^ [5..15] = {[0..5); (10..15]}`
     #[must_use]
-    pub fn overlaps(self, _other: &Range<Idx>) -> bool {
-        todo!()
+    pub fn symmetric_difference(&self, other: &Range<Idx>) -> Range<Idx>
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        self.difference(other).union(&other.difference(self))
     }
 
+    /// Complement of the range, containing all the values that aren't in it
+    ///
+    /// `[0..5)` complement is `{(..0); [5..)}`
     #[must_use]
-    /// Compare the bounds of two ranges
-    pub fn compare_bounds(&self, _other: &Range<Idx>) -> RangesRelation {
-        // Inspired from "Maintaining Knowledge about Temporal Intervals"
-        todo!()
+    pub fn complement(&self) -> Range<Idx>
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        let simplified = self.simplify();
+        let mut items = vec![];
+        let mut gap_start = Some(Bound::Unbounded);
+
+        for item in simplified.components() {
+            let Some((start, end)) = item.range_bounds() else {
+                continue;
+            };
+
+            if let Some(gap_start) = gap_start {
+                if !matches!(start, Bound::Unbounded) {
+                    items.push(ContinuousRange::from_bounds((
+                        gap_start,
+                        reverse_bound(start),
+                    )));
+                }
+            }
+
+            gap_start = match end {
+                Bound::Unbounded => None,
+                end => Some(reverse_bound(end)),
+            };
+        }
+
+        if let Some(gap_start) = gap_start {
+            items.push(ContinuousRange::from_bounds((gap_start, Bound::Unbounded)));
+        }
+
+        Range::composite(items.into_iter().map(Range::Continuous))
+    }
+
+    /// Parts of `within` that aren't covered by the range
+    ///
+    /// Equivalent to `within` intersected with the complement of the range, without computing the complement:
+    /// `{[1..3); [5..7]}` gaps within `[0..10]` are `{[0..1); [3..5); (7..10]}`
+    #[must_use]
+    pub fn gaps(&self, within: &ContinuousRange<Idx>) -> Range<Idx>
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        let simplified = self.simplify();
+        range_from_disjoint(within.gaps(simplified.components()).collect())
+    }
+
+    /// Check if any value is present in both ranges
+    #[must_use]
+    pub fn overlaps(&self, other: &Range<Idx>) -> bool
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        self.components()
+            .iter()
+            .any(|item| other.components().iter().any(|o| item.intersects(o)))
     }
 
+    /// Compare the bounds of two ranges, ignoring the holes in composite ranges.
+    ///
+    /// Each range is compared using its hull, the smallest continuous range covering all of its values, so
+    /// `{[1..2]; [5..6]}` and `[3..4]` are related by [`RangesRelation::StrictlyContains`] even if they share no value.
+    ///
+    /// Returns [`Option::None`] if an empty range is compared to a non-empty range, like
+    /// [`ContinuousRange::compare`].
+    #[must_use]
+    pub fn compare_bounds(&self, other: &Range<Idx>) -> Option<RangesRelation>
+    where
+        Idx: PartialOrd + fmt::Debug,
+    {
+        self.hull().compare(&other.hull())
+    }
+
+    /// Simplify the range to its canonical form
+    ///
+    /// Empty components are removed, the remaining ones are sorted by their start bound and the ones overlapping or
+    /// adjacent to each other are merged. Two simplified ranges containing the same values are equal.
     pub fn simplify_mut(&mut self)
     where
-        Idx: PartialOrd,
+        Idx: PartialOrd + fmt::Debug,
     {
         match self {
             Self::Continuous(r) => r.simplify_mut(),
             Self::Composite(v) => {
-                // TODO: Handle much more cases
-                // - Empty ranges
-                // - Full ranges
-                // - Ranges with only one element
-                // - Merge overlapping ranges
-                // - ...
-                for item in &mut *v {
-                    item.simplify_mut();
+                let mut items: Vec<_> = v
+                    .drain(..)
+                    .map(|item| item.simplify())
+                    .filter(|item| !item.is_empty())
+                    .collect();
+                items.sort_by(|a, b| {
+                    let a_start = a.start().expect("Non-empty range should have a start");
+                    let b_start = b.start().expect("Non-empty range should have a start");
+                    partial_cmp_bounds(&a_start, BoundSide::Start, &b_start, BoundSide::Start)
+                        .unwrap_or(Ordering::Equal)
+                });
+
+                let mut merged: Vec<ContinuousRange<Idx>> = Vec::with_capacity(items.len());
+                for item in items {
+                    match merged.last_mut() {
+                        Some(last) => match last.union(&item) {
+                            Some(union) => *last = union,
+                            None => merged.push(item),
+                        },
+                        None => merged.push(item),
+                    }
                 }
+
+                *self = match merged.len() {
+                    0 => Self::empty(),
+                    1 => Self::Continuous(merged.remove(0)),
+                    _ => Self::Composite(merged),
+                };
             }
         }
     }
@@ -242,13 +421,96 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
     #[must_use]
     pub fn simplify(&self) -> Self
     where
-        Idx: PartialOrd + Clone,
+        Idx: PartialOrd + Clone + fmt::Debug,
     {
         let mut clone = (*self).clone();
         clone.simplify_mut();
         clone
     }
 
+    /// Convert a range over a [`Discrete`] type to its canonical form, where all components are in the
+    /// `[start..end)` form described in [`ContinuousRange::canonicalize`] and then simplified.
+    ///
+    /// `{[1..3]; [4..6]}` becomes `[1..7)`.
+    #[must_use]
+    pub fn canonicalize(&self) -> Self
+    where
+        Idx: Discrete + fmt::Debug,
+    {
+        Range::composite(
+            self.components()
+                .iter()
+                .map(|item| Range::Continuous(item.canonicalize())),
+        )
+    }
+
+    pub fn canonicalize_mut(&mut self)
+    where
+        Idx: Discrete + fmt::Debug,
+    {
+        *self = self.canonicalize();
+    }
+
+    /// Union of two ranges over a [`Discrete`] type, in canonical form
+    ///
+    /// Contrary to [`Range::union`], components following each other with no value between them are merged:
+    /// `[1..3] | [4..6]` is `[1..7)`.
+    #[must_use]
+    pub fn discrete_union(&self, other: &Range<Idx>) -> Range<Idx>
+    where
+        Idx: Discrete + fmt::Debug,
+    {
+        self.union(other).canonicalize()
+    }
+
+    /// Check if two ranges over a [`Discrete`] type contain the same values
+    ///
+    /// `==` compares how the ranges are written, so `[1..5] == [1..6)` is false while they are equal here.
+    #[must_use]
+    pub fn discrete_eq(&self, other: &Range<Idx>) -> bool
+    where
+        Idx: Discrete + fmt::Debug,
+    {
+        self.canonicalize() == other.canonicalize()
+    }
+
+    /// Iterate over all the values of a range of [`Discrete`] values, component by component
+    ///
+    /// Values are produced in order for simplified ranges. Iterating forward over a range without start bound or
+    /// backward over a range without end bound panics.
+    #[must_use]
+    pub fn iter(&self) -> RangeIter<Idx>
+    where
+        Idx: Discrete,
+    {
+        RangeIter::new(self)
+    }
+
+    /// Number of values contained in a range of [`Discrete`] values
+    ///
+    /// Returns [None] if the range is unbounded or if the number of values doesn't fit in an [`u128`].
+    #[must_use]
+    pub fn count(&self) -> Option<u128>
+    where
+        Idx: Discrete,
+    {
+        self.components()
+            .iter()
+            .try_fold(0u128, |count, item| count.checked_add(item.count()?))
+    }
+
+    /// Measure of the range, the sum of the [measures][ContinuousRange::measure] of its components
+    ///
+    /// For a range of dates this is the total duration covered by the range. Unbounded ranges have no measure.
+    #[must_use]
+    pub fn measure<M>(&self) -> Option<M>
+    where
+        Idx: ops::Sub<Output = M>,
+        M: Default + Sum,
+    {
+        self.components().iter().map(ContinuousRange::measure).sum()
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         match self {
@@ -260,7 +522,7 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
     #[must_use]
     pub fn is_full(&self) -> bool
     where
-        Idx: PartialOrd + Clone,
+        Idx: PartialOrd + Clone + fmt::Debug,
     {
         // We simplify to handle case that are complex but represent the full
         // range when simplified like (-inf, 0]; [0, +Inf)
@@ -269,21 +531,69 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
             Self::Composite(v) => v.iter().any(ContinuousRange::is_full),
         }
     }
-}
 
-impl<Idx: PartialOrd + Clone> Add<&Range<Idx>> for Range<Idx> {
-    type Output = Range<Idx>;
+    /// Format the range using a selectable [`crate::Notation`], the [`fmt::Display`] implementation uses
+    /// [`crate::Notation::Native`]
+    #[must_use]
+    pub fn display(&self) -> RangeDisplay<'_, Self>
+    where
+        Idx: fmt::Display,
+    {
+        RangeDisplay::new(self)
+    }
 
-    fn add(self, other: &Range<Idx>) -> Range<Idx> {
-        self.union(other)
+    /// Parse a PostgreSQL multirange literal like `{[1,3), [5,7)}` or a range literal like `[1,5)`
+    ///
+    /// This is the format of multirange types like `int4multirange` or `tstzmultirange` in PostgreSQL text output,
+    /// formatting a range with [`crate::Notation::PostgreSql`] produces it. The result is simplified.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseRangeError`] with the position and cause of the failure if the input isn't a valid multirange
+    /// or range literal or if one of its values can't be parsed.
+    ///
+    /// ```
+    /// use range_ranger::Range;
+    ///
+    /// let range = Range::<i32>::parse_postgresql("{[1,3), [5,7)}").unwrap();
+    /// assert_eq!(range, Range::composite(vec![
+    ///     Range::continuous_end_exclusive(1, 3),
+    ///     Range::continuous_end_exclusive(5, 7),
+    /// ]));
+    /// ```
+    pub fn parse_postgresql(input: &str) -> Result<Self, ParseRangeError<Idx::Err>>
+    where
+        Idx: FromStr + fmt::Debug,
+    {
+        parse_postgresql_multirange(input)
     }
 }
 
-impl<Idx: PartialOrd + Clone> Sub<Range<Idx>> for Range<Idx> {
-    type Output = Range<Idx>;
+/// Difference of two continuous ranges, splitting `range` in two parts when `other` is strictly contained in it
+pub(crate) fn continuous_difference<Idx>(
+    range: &ContinuousRange<Idx>,
+    other: &ContinuousRange<Idx>,
+) -> Vec<ContinuousRange<Idx>>
+where
+    Idx: PartialOrd + Clone + fmt::Debug,
+{
+    if range.compare(other) == Some(RangesRelation::StrictlyContains) {
+        let (start, end) = range.range_bounds().expect("Range contains without bounds");
+        let (other_start, other_end) = other
+            .range_bounds()
+            .expect("Other is contained without bounds");
+        vec![
+            ContinuousRange::from_bounds((start, reverse_bound(other_start))),
+            ContinuousRange::from_bounds((reverse_bound(other_end), end)),
+        ]
+    } else {
+        vec![range.difference(other).unwrap_or_else(|| range.clone())]
+    }
+}
 
-    fn sub(self, other: Range<Idx>) -> Range<Idx> {
-        self.difference(&other)
+impl<Idx: PartialOrd + Clone> From<(Bound<Idx>, Bound<Idx>)> for Range<Idx> {
+    fn from(bounds: (Bound<Idx>, Bound<Idx>)) -> Self {
+        Self::Continuous(bounds.into())
     }
 }
 
@@ -349,6 +659,12 @@ impl<Idx: fmt::Debug> fmt::Debug for Range<Idx> {
     }
 }
 
+impl<Idx: fmt::Display + PartialOrd> fmt::Display for Range<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        RangeDisplay::new(self).fmt(fmt)
+    }
+}
+
 impl<Idx> Default for Range<Idx> {
     fn default() -> Self {
         Self::Continuous(ContinuousRange::Empty)
@@ -0,0 +1,255 @@
+use std::{
+    borrow::Borrow,
+    collections::{btree_map, BTreeMap},
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::Bound,
+};
+
+use crate::{
+    bounds::{end_touches_start, StartBound},
+    entries::{entry_containing, overlapping_entries, range_from_disjoint, stored_bounds},
+    range::continuous_difference,
+    ContinuousRange, Range,
+};
+
+/// A map from non-overlapping [`ContinuousRange`]s to values.
+///
+/// Inserting a value for a range overwrites the values of the parts of existing entries that it overlaps, splitting
+/// them if needed. Entries are indexed by their start bound, making point lookups O(log n).
+///
+/// ```
+/// use range_ranger::{ContinuousRange, RangeMap};
+///
+/// let mut prices = RangeMap::new();
+/// prices.insert(ContinuousRange::from(0), 10);
+/// prices.insert(ContinuousRange::end_exclusive(5, 8), 7);
+///
+/// assert_eq!(prices.get(4), Some(&10));
+/// assert_eq!(prices.get(5), Some(&7));
+/// assert_eq!(prices.get(8), Some(&10));
+/// assert_eq!(prices.get(-1), None);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Hash, PartialEq)]
+pub struct RangeMap<Idx, V> {
+    /// Entries with non-empty ranges, keyed by the start bound of their range
+    entries: BTreeMap<StartBound<Idx>, (ContinuousRange<Idx>, V)>,
+}
+
+impl<Idx: Ord + Clone, V> RangeMap<Idx, V> {
+    /// Create an empty map
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Check if the map contains no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries of the map, in order
+    #[must_use]
+    pub fn iter(&self) -> RangeMapIter<'_, Idx, V> {
+        RangeMapIter {
+            inner: self.entries.values(),
+        }
+    }
+
+    /// Get the value associated with the range containing `value`
+    #[must_use]
+    pub fn get(&self, value: impl Borrow<Idx>) -> Option<&V> {
+        entry_containing(&self.entries, value.borrow()).map(|(_, v)| v)
+    }
+
+    /// Get the range containing `value` and its associated value
+    #[must_use]
+    pub fn get_entry(&self, value: impl Borrow<Idx>) -> Option<(&ContinuousRange<Idx>, &V)> {
+        entry_containing(&self.entries, value.borrow()).map(|(range, v)| (range, v))
+    }
+
+    /// Iterate over the entries whose range shares at least one value with `query`, in order
+    ///
+    /// The ranges are returned as stored, they can extend outside of `query`.
+    pub fn range<'a>(
+        &'a self,
+        query: &ContinuousRange<Idx>,
+    ) -> impl Iterator<Item = (&'a ContinuousRange<Idx>, &'a V)> + 'a {
+        overlapping_entries(&self.entries, query).map(|(range, v)| (range, v))
+    }
+
+    /// The parts of `within` that aren't covered by any entry
+    #[must_use]
+    pub fn gaps(&self, within: &ContinuousRange<Idx>) -> Range<Idx> {
+        let ranges: Vec<_> = self.range(within).map(|(range, _)| range.clone()).collect();
+        range_from_disjoint(within.gaps(&ranges).collect())
+    }
+
+    /// Associate `value` with all the values of `range`, overwriting the overlapping parts of existing entries
+    pub fn insert(&mut self, range: impl Into<ContinuousRange<Idx>>, value: V)
+    where
+        Idx: fmt::Debug,
+        V: Clone,
+    {
+        let range = range.into();
+        if range.is_empty() {
+            return;
+        }
+
+        self.remove(range.clone());
+        self.insert_disjoint(range, value);
+    }
+
+    /// Associate `value` with all the values of `range` like [`RangeMap::insert`], then merge the entry with the
+    /// adjacent ones that have an equal value
+    pub fn insert_coalescing(&mut self, range: impl Into<ContinuousRange<Idx>>, value: V)
+    where
+        Idx: fmt::Debug,
+        V: Clone + PartialEq,
+    {
+        let range = range.into();
+        if range.is_empty() {
+            return;
+        }
+        self.remove(range.clone());
+
+        let (mut start, mut end) = stored_bounds(&range);
+        let range_key = StartBound(start.cloned());
+        let mut merged = Vec::new();
+
+        if let Some((key, (previous, previous_value))) =
+            self.entries.range(..range_key.clone()).next_back()
+        {
+            let (previous_start, previous_end) = stored_bounds(previous);
+            if *previous_value == value && end_touches_start(previous_end, start) {
+                start = previous_start;
+                merged.push(key);
+            }
+        }
+
+        if let Some((key, (next, next_value))) = self
+            .entries
+            .range((Bound::Excluded(range_key), Bound::Unbounded))
+            .next()
+        {
+            let (next_start, next_end) = stored_bounds(next);
+            if *next_value == value && end_touches_start(end, next_start) {
+                end = next_end;
+                merged.push(key);
+            }
+        }
+
+        let merged: Vec<_> = merged.into_iter().cloned().collect();
+        let range = ContinuousRange::from_bounds((start, end));
+        for key in &merged {
+            self.entries.remove(key);
+        }
+        self.insert_disjoint(range, value);
+    }
+
+    /// Remove all the values of `range` from the map, splitting the entries that it partially covers
+    #[allow(clippy::missing_panics_doc)]
+    pub fn remove(&mut self, range: impl Into<ContinuousRange<Idx>>)
+    where
+        Idx: fmt::Debug,
+        V: Clone,
+    {
+        let range = range.into();
+        let overlapping: Vec<_> = overlapping_entries(&self.entries, &range)
+            .map(|(existing, _)| StartBound(stored_bounds(existing).0.cloned()))
+            .collect();
+
+        for key in overlapping {
+            let (existing, value) = self.entries.remove(&key).expect("Overlapping entry exists");
+            for part in continuous_difference(&existing, &range) {
+                if !part.is_empty() {
+                    self.insert_disjoint(part, value.clone());
+                }
+            }
+        }
+    }
+
+    /// Insert an entry with a non-empty range that is known to not overlap any entry of the map
+    fn insert_disjoint(&mut self, range: ContinuousRange<Idx>, value: V) {
+        let key = StartBound(stored_bounds(&range).0.cloned());
+        self.entries.insert(key, (range, value));
+    }
+}
+
+impl<Idx: Ord + Clone, V> Default for RangeMap<Idx, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Idx: fmt::Debug, V: fmt::Debug> fmt::Debug for RangeMap<Idx, V> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map()
+            .entries(self.entries.values().map(|(range, value)| (range, value)))
+            .finish()
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V: Clone> FromIterator<(ContinuousRange<Idx>, V)>
+    for RangeMap<Idx, V>
+{
+    /// Later entries overwrite the overlapping parts of earlier ones
+    fn from_iter<T: IntoIterator<Item = (ContinuousRange<Idx>, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<Idx: Ord + Clone + fmt::Debug, V: Clone> Extend<(ContinuousRange<Idx>, V)>
+    for RangeMap<Idx, V>
+{
+    fn extend<T: IntoIterator<Item = (ContinuousRange<Idx>, V)>>(&mut self, iter: T) {
+        for (range, value) in iter {
+            self.insert(range, value);
+        }
+    }
+}
+
+impl<'a, Idx: Ord + Clone, V> IntoIterator for &'a RangeMap<Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+    type IntoIter = RangeMapIter<'a, Idx, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`RangeMap`], in order.
+///
+/// Created by [`RangeMap::iter`].
+#[derive(Clone, Debug)]
+pub struct RangeMapIter<'a, Idx, V> {
+    inner: btree_map::Values<'a, StartBound<Idx>, (ContinuousRange<Idx>, V)>,
+}
+
+impl<'a, Idx, V> Iterator for RangeMapIter<'a, Idx, V> {
+    type Item = (&'a ContinuousRange<Idx>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(range, value)| (range, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<Idx, V> DoubleEndedIterator for RangeMapIter<'_, Idx, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(range, value)| (range, value))
+    }
+}
+
+impl<Idx, V> ExactSizeIterator for RangeMapIter<'_, Idx, V> {}
+
+impl<Idx, V> FusedIterator for RangeMapIter<'_, Idx, V> {}
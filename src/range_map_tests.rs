@@ -0,0 +1,393 @@
+use crate::{ContinuousRange, RangeMap};
+
+fn entries<V: Clone>(map: &RangeMap<i32, V>) -> Vec<(ContinuousRange<i32>, V)> {
+    map.iter()
+        .map(|(range, value)| (range.clone(), value.clone()))
+        .collect()
+}
+
+mod test_insert {
+    use super::entries;
+    use crate::{ContinuousRange, RangeMap};
+
+    #[test]
+    pub fn disjoint_are_sorted() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(10, 12), 'b');
+        map.insert(ContinuousRange::inclusive(1, 3), 'a');
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::inclusive(1, 3), 'a'),
+                (ContinuousRange::inclusive(10, 12), 'b'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn empty_is_ignored() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::empty(), 'a');
+        map.insert(ContinuousRange::inclusive(5, 1), 'a');
+        assert_eq!(map.is_empty(), true);
+    }
+
+    #[test]
+    pub fn splits_existing() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 10), 'a');
+        map.insert(ContinuousRange::exclusive(3, 5), 'b');
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::inclusive(1, 3), 'a'),
+                (ContinuousRange::exclusive(3, 5), 'b'),
+                (ContinuousRange::inclusive(5, 10), 'a'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn overwrites_overlapping() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 5), 'a');
+        map.insert(ContinuousRange::inclusive(6, 8), 'b');
+        map.insert(ContinuousRange::inclusive(10, 15), 'c');
+        map.insert(ContinuousRange::inclusive(3, 12), 'd');
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::end_exclusive(1, 3), 'a'),
+                (ContinuousRange::inclusive(3, 12), 'd'),
+                (ContinuousRange::start_exclusive(12, 15), 'c'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn shared_bound() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 5), 'a');
+        map.insert(ContinuousRange::inclusive(5, 8), 'b');
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::end_exclusive(1, 5), 'a'),
+                (ContinuousRange::inclusive(5, 8), 'b'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn same_range() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 5), 'a');
+        map.insert(ContinuousRange::inclusive(1, 5), 'b');
+        assert_eq!(entries(&map), vec![(ContinuousRange::inclusive(1, 5), 'b')]);
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::full(), 'a');
+        map.insert(ContinuousRange::inclusive(0, 10), 'b');
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::to_exclusive(0), 'a'),
+                (ContinuousRange::inclusive(0, 10), 'b'),
+                (ContinuousRange::from_exclusive(10), 'a'),
+            ]
+        );
+
+        map.insert(ContinuousRange::from(5), 'c');
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::to_exclusive(0), 'a'),
+                (ContinuousRange::end_exclusive(0, 5), 'b'),
+                (ContinuousRange::from(5), 'c'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn adjacent_are_kept_apart() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::end_exclusive(1, 5), 'a');
+        map.insert(ContinuousRange::end_exclusive(5, 8), 'a');
+        assert_eq!(map.iter().len(), 2);
+    }
+}
+
+mod test_insert_coalescing {
+    use super::entries;
+    use crate::{ContinuousRange, RangeMap};
+
+    #[test]
+    pub fn adjacent_equal_values() {
+        let mut map = RangeMap::new();
+        map.insert_coalescing(ContinuousRange::end_exclusive(1, 5), 'a');
+        map.insert_coalescing(ContinuousRange::end_exclusive(10, 15), 'a');
+        map.insert_coalescing(ContinuousRange::end_exclusive(5, 10), 'a');
+        assert_eq!(
+            entries(&map),
+            vec![(ContinuousRange::end_exclusive(1, 15), 'a')]
+        );
+    }
+
+    #[test]
+    pub fn different_values() {
+        let mut map = RangeMap::new();
+        map.insert_coalescing(ContinuousRange::end_exclusive(1, 5), 'a');
+        map.insert_coalescing(ContinuousRange::end_exclusive(5, 10), 'b');
+        assert_eq!(map.iter().len(), 2);
+    }
+
+    #[test]
+    pub fn not_adjacent() {
+        let mut map = RangeMap::new();
+        map.insert_coalescing(ContinuousRange::end_exclusive(1, 5), 'a');
+        map.insert_coalescing(ContinuousRange::exclusive(5, 10), 'a');
+        assert_eq!(map.iter().len(), 2);
+    }
+
+    #[test]
+    pub fn overwrite_with_same_value() {
+        let mut map = RangeMap::new();
+        map.insert_coalescing(ContinuousRange::inclusive(1, 10), 'a');
+        map.insert_coalescing(ContinuousRange::inclusive(3, 5), 'a');
+        assert_eq!(
+            entries(&map),
+            vec![(ContinuousRange::inclusive(1, 10), 'a')]
+        );
+    }
+
+    #[test]
+    pub fn overlapping_equal_values() {
+        let mut map = RangeMap::new();
+        map.insert_coalescing(ContinuousRange::inclusive(1, 5), 'a');
+        map.insert_coalescing(ContinuousRange::inclusive(3, 8), 'a');
+        assert_eq!(entries(&map), vec![(ContinuousRange::inclusive(1, 8), 'a')]);
+    }
+}
+
+mod test_remove {
+    use super::entries;
+    use crate::{ContinuousRange, RangeMap};
+
+    #[test]
+    pub fn splits() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 10), 'a');
+        map.remove(ContinuousRange::single(5));
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::end_exclusive(1, 5), 'a'),
+                (ContinuousRange::start_exclusive(5, 10), 'a'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn across_entries() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 3), 'a');
+        map.insert(ContinuousRange::inclusive(5, 7), 'b');
+        map.insert(ContinuousRange::inclusive(9, 11), 'c');
+        map.remove(ContinuousRange::exclusive(2, 10));
+        assert_eq!(
+            entries(&map),
+            vec![
+                (ContinuousRange::inclusive(1, 2), 'a'),
+                (ContinuousRange::inclusive(10, 11), 'c'),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn full() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::inclusive(1, 3), 'a');
+        map.remove(ContinuousRange::full());
+        assert_eq!(map.is_empty(), true);
+    }
+}
+
+mod test_get {
+    use crate::{ContinuousRange, RangeMap};
+
+    #[test]
+    pub fn bounds() {
+        let map: RangeMap<_, _> = vec![
+            (ContinuousRange::end_exclusive(1, 3), 'a'),
+            (ContinuousRange::start_exclusive(5, 7), 'b'),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(map.get(0), None);
+        assert_eq!(map.get(1), Some(&'a'));
+        assert_eq!(map.get(3), None);
+        assert_eq!(map.get(5), None);
+        assert_eq!(map.get(7), Some(&'b'));
+        assert_eq!(map.get(8), None);
+    }
+
+    #[test]
+    pub fn entry() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::to(0), 'a');
+        assert_eq!(map.get_entry(-100), Some((&ContinuousRange::to(0), &'a')));
+        assert_eq!(map.get_entry(1), None);
+    }
+}
+
+mod test_range {
+    use crate::{ContinuousRange, RangeMap};
+
+    #[test]
+    pub fn overlapping_entries() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::end_exclusive(1, 3), 'a');
+        map.insert(ContinuousRange::inclusive(5, 7), 'b');
+        map.insert(ContinuousRange::from_exclusive(9), 'c');
+
+        let values: Vec<_> = map
+            .range(&ContinuousRange::inclusive(2, 9))
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(values, vec!['a', 'b']);
+
+        let values: Vec<_> = map
+            .range(&ContinuousRange::exclusive(3, 5))
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(values, Vec::<char>::new());
+    }
+}
+
+mod test_gaps {
+    use crate::{ContinuousRange, Range, RangeMap};
+
+    fn map() -> RangeMap<i32, char> {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::end_exclusive(1, 3), 'a');
+        map.insert(ContinuousRange::inclusive(5, 7), 'b');
+        map
+    }
+
+    #[test]
+    pub fn within_bounded() {
+        assert_eq!(
+            map().gaps(&ContinuousRange::inclusive(0, 10)),
+            Range::Composite(vec![
+                ContinuousRange::end_exclusive(0, 1),
+                ContinuousRange::end_exclusive(3, 5),
+                ContinuousRange::start_exclusive(7, 10),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn within_unbounded() {
+        assert_eq!(
+            map().gaps(&ContinuousRange::full()),
+            Range::Composite(vec![
+                ContinuousRange::to_exclusive(1),
+                ContinuousRange::end_exclusive(3, 5),
+                ContinuousRange::from_exclusive(7),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn inside_entry() {
+        assert_eq!(
+            map().gaps(&ContinuousRange::inclusive(5, 6)),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn partially_covered() {
+        assert_eq!(
+            map().gaps(&ContinuousRange::inclusive(2, 5)),
+            Range::continuous_end_exclusive(3, 5)
+        );
+    }
+
+    #[test]
+    pub fn unbounded_entries() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::to(-5), 'a');
+        map.insert(ContinuousRange::from(0), 'b');
+
+        assert_eq!(
+            map.gaps(&ContinuousRange::full()),
+            Range::continuous_exclusive(-5, 0)
+        );
+        assert_eq!(
+            map.gaps(&ContinuousRange::inclusive(-10, 10)),
+            Range::continuous_exclusive(-5, 0)
+        );
+        assert_eq!(map.gaps(&ContinuousRange::inclusive(1, 10)), Range::empty());
+    }
+
+    #[test]
+    pub fn unbounded_start_entry() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::to_exclusive(10), 'a');
+
+        assert_eq!(
+            map.gaps(&ContinuousRange::inclusive(0, 20)),
+            Range::continuous(10, 20)
+        );
+    }
+
+    #[test]
+    pub fn empty_map() {
+        assert_eq!(
+            RangeMap::<i32, char>::new().gaps(&ContinuousRange::to(5)),
+            Range::to(5)
+        );
+        assert_eq!(map().gaps(&ContinuousRange::empty()), Range::empty());
+    }
+}
+
+mod test_read_without_debug {
+    use crate::{ContinuousRange, RangeMap};
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Version(u32);
+
+    #[test]
+    pub fn read_api() {
+        let map = RangeMap::<Version, char>::default();
+        assert_eq!(map.is_empty(), true);
+        assert_eq!(map.get(Version(1)), None);
+        assert_eq!(map.get_entry(Version(1)).is_none(), true);
+        assert_eq!(map.iter().count(), 0);
+        assert_eq!((&map).into_iter().count(), 0);
+        assert_eq!(map.range(&ContinuousRange::full()).count(), 0);
+        assert_eq!(
+            map.gaps(&ContinuousRange::from(Version(1)))
+                .components()
+                .len(),
+            1
+        );
+    }
+}
+
+mod test_fmt_debug {
+    use crate::{ContinuousRange, RangeMap};
+
+    #[test]
+    pub fn entries() {
+        let mut map = RangeMap::new();
+        map.insert(ContinuousRange::end_exclusive(1, 3), 'a');
+        map.insert(ContinuousRange::from(5), 'b');
+        assert_eq!(format!("{map:?}"), "{[1..3): 'a', [5..): 'b'}");
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    borrow::Borrow,
+    collections::{btree_map, BTreeMap},
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::Bound,
+};
+
+use crate::{
+    bounds::{end_touches_start, max_end, reverse_bound, StartBound},
+    entries::{entry_containing, overlapping_entries, range_from_disjoint, stored_bounds},
+    ContinuousRange, Range,
+};
+
+/// A set of values stored as sorted, disjoint [`ContinuousRange`]s.
+///
+/// Overlapping or adjacent ranges are merged when inserted and removing a range splits the ranges it partially covers,
+/// so the set always holds the fewest ranges needed. Ranges are indexed by their start bound, making point lookups
+/// O(log n) instead of the linear scan of [`Range::contains`].
+///
+/// ```
+/// use range_ranger::{ContinuousRange, RangeSet};
+///
+/// let mut set = RangeSet::new();
+/// set.insert(1..5);
+/// set.insert(5..=8);
+/// set.remove(3..4);
+///
+/// assert!(set.contains(8));
+/// assert!(!set.contains(3));
+/// assert!(set.iter().eq(&[
+///     ContinuousRange::end_exclusive(1, 3),
+///     ContinuousRange::inclusive(4, 8),
+/// ]));
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Hash, PartialEq)]
+pub struct RangeSet<Idx> {
+    /// Non-empty ranges, keyed by their start bound
+    ranges: BTreeMap<StartBound<Idx>, ContinuousRange<Idx>>,
+}
+
+impl<Idx: Ord + Clone> RangeSet<Idx> {
+    /// Create an empty set
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// Check if the set contains no values
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Iterate over the disjoint ranges of the set, in order
+    #[must_use]
+    pub fn iter(&self) -> RangeSetIter<'_, Idx> {
+        RangeSetIter {
+            inner: self.ranges.values(),
+        }
+    }
+
+    /// Check if the set contains the provided value
+    #[must_use]
+    pub fn contains(&self, value: impl Borrow<Idx>) -> bool {
+        entry_containing(&self.ranges, value.borrow()).is_some()
+    }
+
+    /// Add all the values of `range` to the set, merging it with the overlapping and adjacent ranges
+    pub fn insert(&mut self, range: impl Into<ContinuousRange<Idx>>) {
+        let range = range.into();
+        let Some((mut start, mut end)) = range.range_bounds().filter(|_| !range.is_empty()) else {
+            return;
+        };
+        let mut merged = Vec::new();
+
+        if let Some((key, previous)) = self.ranges.range(..=StartBound(start.cloned())).next_back()
+        {
+            let (previous_start, previous_end) = stored_bounds(previous);
+            if end_touches_start(previous_end, start) {
+                start = previous_start;
+                end = max_end(previous_end, end);
+                merged.push(key);
+            }
+        }
+
+        let after = (
+            Bound::Excluded(StartBound(start.cloned())),
+            Bound::Unbounded,
+        );
+        for (key, next) in self.ranges.range(after) {
+            let (next_start, next_end) = stored_bounds(next);
+            if !end_touches_start(end, next_start) {
+                break;
+            }
+            end = max_end(end, next_end);
+            merged.push(key);
+        }
+
+        let merged: Vec<_> = merged.into_iter().cloned().collect();
+        let range = ContinuousRange::from_bounds((start, end));
+        for key in &merged {
+            self.ranges.remove(key);
+        }
+        self.insert_disjoint(range);
+    }
+
+    /// Remove all the values of `range` from the set, splitting the ranges that it partially covers
+    pub fn remove(&mut self, range: impl Into<ContinuousRange<Idx>>) {
+        let range = range.into();
+        let Some((start, end)) = range.range_bounds().filter(|_| !range.is_empty()) else {
+            return;
+        };
+
+        let overlapping: Vec<_> = self.overlapping(&range).cloned().collect();
+        for existing in overlapping {
+            let (existing_start, existing_end) = stored_bounds(&existing);
+            self.ranges.remove(&StartBound(existing_start.cloned()));
+
+            if start != Bound::Unbounded {
+                self.insert_disjoint(ContinuousRange::from_bounds((
+                    existing_start,
+                    reverse_bound(start),
+                )));
+            }
+            if end != Bound::Unbounded {
+                self.insert_disjoint(ContinuousRange::from_bounds((
+                    reverse_bound(end),
+                    existing_end,
+                )));
+            }
+        }
+    }
+
+    /// Iterate over the ranges of the set that share at least one value with `range`, in order
+    pub fn overlapping<'a>(
+        &'a self,
+        range: &ContinuousRange<Idx>,
+    ) -> impl Iterator<Item = &'a ContinuousRange<Idx>> + 'a {
+        overlapping_entries(&self.ranges, range)
+    }
+
+    /// Insert a non-empty range that is known to not touch any range of the set, empty ranges are ignored
+    fn insert_disjoint(&mut self, range: ContinuousRange<Idx>) {
+        if let Some((start, _)) = range.range_bounds().filter(|_| !range.is_empty()) {
+            let key = StartBound(start.cloned());
+            self.ranges.insert(key, range);
+        }
+    }
+}
+
+impl<Idx: Ord + Clone> Default for RangeSet<Idx> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Idx: fmt::Debug> fmt::Debug for RangeSet<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set().entries(self.ranges.values()).finish()
+    }
+}
+
+impl<Idx: Ord + Clone> From<Range<Idx>> for RangeSet<Idx> {
+    fn from(range: Range<Idx>) -> Self {
+        match range {
+            Range::Continuous(r) => std::iter::once(r).collect(),
+            Range::Composite(v) => v.into_iter().collect(),
+        }
+    }
+}
+
+impl<Idx: Ord + Clone> From<ContinuousRange<Idx>> for RangeSet<Idx> {
+    fn from(range: ContinuousRange<Idx>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<Idx> From<RangeSet<Idx>> for Range<Idx> {
+    /// The ranges of the set are already sorted and merged, so the result is simplified
+    fn from(set: RangeSet<Idx>) -> Self {
+        range_from_disjoint(set.ranges.into_values().collect())
+    }
+}
+
+impl<Idx: Ord + Clone> FromIterator<ContinuousRange<Idx>> for RangeSet<Idx> {
+    fn from_iter<T: IntoIterator<Item = ContinuousRange<Idx>>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<Idx: Ord + Clone> Extend<ContinuousRange<Idx>> for RangeSet<Idx> {
+    fn extend<T: IntoIterator<Item = ContinuousRange<Idx>>>(&mut self, iter: T) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<'a, Idx: Ord + Clone> IntoIterator for &'a RangeSet<Idx> {
+    type Item = &'a ContinuousRange<Idx>;
+    type IntoIter = RangeSetIter<'a, Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the ranges of a [`RangeSet`], in order.
+///
+/// Created by [`RangeSet::iter`].
+#[derive(Clone, Debug)]
+pub struct RangeSetIter<'a, Idx> {
+    inner: btree_map::Values<'a, StartBound<Idx>, ContinuousRange<Idx>>,
+}
+
+impl<'a, Idx> Iterator for RangeSetIter<'a, Idx> {
+    type Item = &'a ContinuousRange<Idx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<Idx> DoubleEndedIterator for RangeSetIter<'_, Idx> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<Idx> ExactSizeIterator for RangeSetIter<'_, Idx> {}
+
+impl<Idx> FusedIterator for RangeSetIter<'_, Idx> {}
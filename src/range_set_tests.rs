@@ -0,0 +1,323 @@
+use crate::{ContinuousRange, RangeSet};
+
+fn ranges<Idx: Ord + Clone>(set: &RangeSet<Idx>) -> Vec<ContinuousRange<Idx>> {
+    set.iter().cloned().collect()
+}
+
+mod test_insert {
+    use super::ranges;
+    use crate::{ContinuousRange, RangeSet};
+
+    #[test]
+    pub fn disjoint_are_sorted() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::inclusive(10, 12));
+        set.insert(ContinuousRange::inclusive(1, 3));
+        set.insert(ContinuousRange::single(6));
+        assert_eq!(
+            ranges(&set),
+            vec![
+                ContinuousRange::inclusive(1, 3),
+                ContinuousRange::single(6),
+                ContinuousRange::inclusive(10, 12),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn empty_is_ignored() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::<i32>::empty());
+        set.insert(ContinuousRange::inclusive(5, 1));
+        set.insert(ContinuousRange::exclusive(1, 1));
+        assert_eq!(set.is_empty(), true);
+    }
+
+    #[test]
+    pub fn overlapping_are_merged() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::inclusive(1, 5));
+        set.insert(ContinuousRange::inclusive(10, 15));
+        set.insert(ContinuousRange::inclusive(3, 12));
+        assert_eq!(ranges(&set), vec![ContinuousRange::inclusive(1, 15)]);
+    }
+
+    #[test]
+    pub fn adjacent_are_merged() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::end_exclusive(1, 5));
+        set.insert(ContinuousRange::inclusive(5, 8));
+        set.insert(ContinuousRange::from_exclusive(8));
+        assert_eq!(ranges(&set), vec![ContinuousRange::from(1)]);
+    }
+
+    #[test]
+    pub fn excluded_ends_are_not_merged() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::end_exclusive(1, 5));
+        set.insert(ContinuousRange::start_exclusive(5, 8));
+        assert_eq!(
+            ranges(&set),
+            vec![
+                ContinuousRange::end_exclusive(1, 5),
+                ContinuousRange::start_exclusive(5, 8),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn contained_is_absorbed() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::inclusive(1, 10));
+        set.insert(ContinuousRange::exclusive(3, 4));
+        set.insert(ContinuousRange::single(10));
+        assert_eq!(ranges(&set), vec![ContinuousRange::inclusive(1, 10)]);
+    }
+
+    #[test]
+    pub fn covering_replaces_many() {
+        let mut set = RangeSet::new();
+        for i in 0..10 {
+            set.insert(ContinuousRange::single(i * 10));
+        }
+        set.insert(ContinuousRange::to_exclusive(45));
+        assert_eq!(
+            ranges(&set),
+            vec![
+                ContinuousRange::to_exclusive(45),
+                ContinuousRange::single(50),
+                ContinuousRange::single(60),
+                ContinuousRange::single(70),
+                ContinuousRange::single(80),
+                ContinuousRange::single(90),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn std_ranges() {
+        let mut set = RangeSet::new();
+        set.insert(1..5);
+        set.insert(5..=8);
+        assert_eq!(ranges(&set), vec![ContinuousRange::inclusive(1, 8)]);
+    }
+
+    #[test]
+    pub fn full() {
+        let mut set = RangeSet::new();
+        set.insert(ContinuousRange::inclusive(1, 8));
+        set.insert(ContinuousRange::full());
+        assert_eq!(ranges(&set), vec![ContinuousRange::full()]);
+    }
+}
+
+mod test_remove {
+    use super::ranges;
+    use crate::{ContinuousRange, RangeSet};
+
+    #[test]
+    pub fn splits() {
+        let mut set = RangeSet::from(ContinuousRange::inclusive(1, 10));
+        set.remove(ContinuousRange::inclusive(4, 6));
+        assert_eq!(
+            ranges(&set),
+            vec![
+                ContinuousRange::end_exclusive(1, 4),
+                ContinuousRange::start_exclusive(6, 10),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn single_value() {
+        let mut set = RangeSet::from(ContinuousRange::inclusive(1, 10));
+        set.remove(ContinuousRange::single(1));
+        set.remove(ContinuousRange::single(5));
+        assert_eq!(
+            ranges(&set),
+            vec![
+                ContinuousRange::exclusive(1, 5),
+                ContinuousRange::start_exclusive(5, 10),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn across_ranges() {
+        let mut set: RangeSet<_> = vec![
+            ContinuousRange::inclusive(1, 3),
+            ContinuousRange::inclusive(5, 7),
+            ContinuousRange::inclusive(9, 11),
+        ]
+        .into_iter()
+        .collect();
+        set.remove(ContinuousRange::exclusive(2, 10));
+        assert_eq!(
+            ranges(&set),
+            vec![
+                ContinuousRange::inclusive(1, 2),
+                ContinuousRange::inclusive(10, 11),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let mut set = RangeSet::from(ContinuousRange::inclusive(1, 10));
+        set.remove(ContinuousRange::to_exclusive(3));
+        set.remove(ContinuousRange::from_exclusive(8));
+        assert_eq!(ranges(&set), vec![ContinuousRange::inclusive(3, 8)]);
+
+        set.remove(ContinuousRange::full());
+        assert_eq!(set.is_empty(), true);
+    }
+
+    #[test]
+    pub fn touching_is_kept() {
+        let mut set = RangeSet::from(ContinuousRange::inclusive(1, 10));
+        set.remove(ContinuousRange::exclusive(10, 20));
+        set.remove(ContinuousRange::to_exclusive(1));
+        assert_eq!(ranges(&set), vec![ContinuousRange::inclusive(1, 10)]);
+    }
+
+    #[test]
+    pub fn empty() {
+        let mut set = RangeSet::from(ContinuousRange::inclusive(1, 10));
+        set.remove(ContinuousRange::empty());
+        set.remove(ContinuousRange::inclusive(5, 4));
+        assert_eq!(ranges(&set), vec![ContinuousRange::inclusive(1, 10)]);
+    }
+}
+
+mod test_contains {
+    use crate::{ContinuousRange, RangeSet};
+
+    #[test]
+    pub fn bounds() {
+        let set: RangeSet<_> = vec![
+            ContinuousRange::end_exclusive(1, 3),
+            ContinuousRange::start_exclusive(5, 7),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.contains(0), false);
+        assert_eq!(set.contains(1), true);
+        assert_eq!(set.contains(2), true);
+        assert_eq!(set.contains(3), false);
+        assert_eq!(set.contains(5), false);
+        assert_eq!(set.contains(6), true);
+        assert_eq!(set.contains(7), true);
+        assert_eq!(set.contains(8), false);
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let set = RangeSet::from(ContinuousRange::to(0));
+        assert_eq!(set.contains(i32::MIN), true);
+        assert_eq!(set.contains(0), true);
+        assert_eq!(set.contains(1), false);
+    }
+
+    #[test]
+    pub fn empty() {
+        let set = RangeSet::<i32>::new();
+        assert_eq!(set.contains(0), false);
+    }
+}
+
+mod test_overlapping {
+    use crate::{ContinuousRange, RangeSet};
+
+    fn set() -> RangeSet<i32> {
+        vec![
+            ContinuousRange::end_exclusive(1, 3),
+            ContinuousRange::inclusive(5, 7),
+            ContinuousRange::from_exclusive(9),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn overlapping(range: &ContinuousRange<i32>) -> Vec<ContinuousRange<i32>> {
+        set().overlapping(range).cloned().collect()
+    }
+
+    #[test]
+    pub fn previous() {
+        assert_eq!(
+            overlapping(&ContinuousRange::inclusive(2, 4)),
+            vec![ContinuousRange::end_exclusive(1, 3)]
+        );
+    }
+
+    #[test]
+    pub fn many() {
+        assert_eq!(
+            overlapping(&ContinuousRange::inclusive(2, 10)),
+            vec![
+                ContinuousRange::end_exclusive(1, 3),
+                ContinuousRange::inclusive(5, 7),
+                ContinuousRange::from_exclusive(9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn touching_bounds() {
+        assert_eq!(overlapping(&ContinuousRange::inclusive(3, 4)), vec![]);
+        assert_eq!(overlapping(&ContinuousRange::exclusive(7, 9)), vec![]);
+        assert_eq!(
+            overlapping(&ContinuousRange::inclusive(7, 9)),
+            vec![ContinuousRange::inclusive(5, 7)]
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(overlapping(&ContinuousRange::empty()), vec![]);
+        assert_eq!(overlapping(&ContinuousRange::inclusive(6, 5)), vec![]);
+    }
+
+    #[test]
+    pub fn full() {
+        assert_eq!(overlapping(&ContinuousRange::full()).len(), 3);
+    }
+}
+
+mod test_conversions {
+    use crate::{ContinuousRange, Range, RangeSet};
+
+    #[test]
+    pub fn from_range_is_merged() {
+        let range = Range::Composite(vec![
+            ContinuousRange::inclusive(5, 8),
+            ContinuousRange::end_exclusive(1, 5),
+            ContinuousRange::single(10),
+        ]);
+        let merged: Range<_> = RangeSet::from(range).into();
+        assert_eq!(
+            merged,
+            Range::composite(vec![Range::continuous(1, 8), Range::single(10)])
+        );
+    }
+
+    #[test]
+    pub fn to_range() {
+        let empty: Range<i32> = RangeSet::new().into();
+        assert_eq!(empty, Range::empty());
+        let to: Range<_> = RangeSet::from(ContinuousRange::to(5)).into();
+        assert_eq!(to, Range::to(5));
+    }
+
+    #[test]
+    pub fn fmt_debug() {
+        let set: RangeSet<_> = vec![
+            ContinuousRange::end_exclusive(1, 3),
+            ContinuousRange::from(5),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(format!("{set:?}"), "{[1..3), [5..)}");
+    }
+}
@@ -3,6 +3,7 @@
 /// This is based on [Allen's interval algebra](https://en.wikipedia.org/wiki/Allen%27s_interval_algebra) for temporal
 /// intervals.
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RangesRelation {
     /// The first range is strictly before the second one with no overlap
     ///
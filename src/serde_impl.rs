@@ -0,0 +1,102 @@
+//! [`serde`] support, enabled by the `serde` feature.
+//!
+//! Human-readable formats like JSON use the text notation of [`fmt::Display`] and [`FromStr`]: `[1..5)` is
+//! `"[1..5)"` and `{[1..2]; (5..)}` is `"{[1..2]; (5..)}"`. Values that would be ambiguous in the notation are
+//! quoted, so any value round-trips whatever its text representation.
+//!
+//! Compact formats use a structured form instead: a [`ContinuousRange`] is an optional pair of [`Bound`], `None` being
+//! the empty range, and a [`Range`] is a sequence of such pairs, one for each non-empty component.
+
+use std::{fmt, marker::PhantomData, ops::Bound, str::FromStr};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{ContinuousRange, Range};
+
+/// Deserialize a value from a string using its [`FromStr`] implementation
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("a range in the text notation")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<Idx> Serialize for ContinuousRange<Idx>
+where
+    Idx: Serialize + PartialOrd + fmt::Display,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.range_bounds().serialize(serializer)
+        }
+    }
+}
+
+impl<'de, Idx> Deserialize<'de> for ContinuousRange<Idx>
+where
+    Idx: Deserialize<'de> + FromStr + PartialOrd + Clone,
+    Idx::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_str(FromStrVisitor(PhantomData));
+        }
+
+        let bounds = Option::<(Bound<Idx>, Bound<Idx>)>::deserialize(deserializer)?;
+        Ok(bounds.map_or(ContinuousRange::Empty, |(start, end)| {
+            ContinuousRange::from_bounds((start.as_ref(), end.as_ref()))
+        }))
+    }
+}
+
+impl<Idx> Serialize for Range<Idx>
+where
+    Idx: Serialize + PartialOrd + Clone + fmt::Display,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+
+        // Collected first as binary formats need to know the length of the sequence upfront
+        let components: Vec<_> = self
+            .components()
+            .iter()
+            .filter_map(ContinuousRange::range_bounds)
+            .collect();
+        components.serialize(serializer)
+    }
+}
+
+impl<'de, Idx> Deserialize<'de> for Range<Idx>
+where
+    Idx: Deserialize<'de> + FromStr + PartialOrd + Clone + fmt::Debug,
+    Idx::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_str(FromStrVisitor(PhantomData));
+        }
+
+        let components = Vec::<(Bound<Idx>, Bound<Idx>)>::deserialize(deserializer)?;
+        Ok(Range::composite(components.iter().map(|(start, end)| {
+            Range::Continuous(ContinuousRange::from_bounds((start.as_ref(), end.as_ref())))
+        })))
+    }
+}
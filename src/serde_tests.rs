@@ -0,0 +1,235 @@
+mod test_continuous {
+    use crate::ContinuousRange;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Readable, Token};
+
+    #[test]
+    pub fn empty() {
+        assert_tokens(
+            &ContinuousRange::<i32>::empty().readable(),
+            &[Token::Str("[]")],
+        );
+        assert_tokens(&ContinuousRange::<i32>::empty().compact(), &[Token::None]);
+    }
+
+    #[test]
+    pub fn end_exclusive() {
+        assert_tokens(
+            &ContinuousRange::end_exclusive(1, 5).readable(),
+            &[Token::Str("[1..5)")],
+        );
+        assert_tokens(
+            &ContinuousRange::end_exclusive(1, 5).compact(),
+            &[
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I32(1),
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Excluded",
+                },
+                Token::I32(5),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    pub fn to() {
+        assert_tokens(&ContinuousRange::to(5).readable(), &[Token::Str("(..5]")]);
+        assert_tokens(
+            &ContinuousRange::to(5).compact(),
+            &[
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::UnitVariant {
+                    name: "Bound",
+                    variant: "Unbounded",
+                },
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I32(5),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    pub fn invalid_text() {
+        assert_de_tokens_error::<Readable<ContinuousRange<i32>>>(
+            &[Token::Str("[1..5")],
+            "expected `]` or `)` at position 5",
+        );
+        assert_de_tokens_error::<Readable<ContinuousRange<i32>>>(
+            &[Token::I32(1)],
+            "invalid type: integer `1`, expected a range in the text notation",
+        );
+    }
+
+    #[test]
+    pub fn json() {
+        let ranges = [
+            ContinuousRange::empty(),
+            ContinuousRange::full(),
+            ContinuousRange::end_exclusive(1.5, 5.0),
+            ContinuousRange::from_exclusive(-1.0),
+        ];
+
+        for range in ranges {
+            let json = serde_json::to_string(&range).unwrap();
+            assert_eq!(
+                serde_json::from_str::<ContinuousRange<f64>>(&json).unwrap(),
+                range
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&ContinuousRange::end_exclusive(1, 5)).unwrap(),
+            r#""[1..5)""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ContinuousRange::<i32>::empty()).unwrap(),
+            r#""[]""#
+        );
+    }
+
+    #[test]
+    pub fn values_with_notation_characters() {
+        let range = ContinuousRange::inclusive("a..b".to_string(), "c;d]".to_string());
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ContinuousRange<String>>(&json).unwrap(),
+            range
+        );
+        assert_tokens(
+            &range.compact(),
+            &[
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::Str("a..b"),
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::Str("c;d]"),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
+
+mod test_range {
+    use crate::{ContinuousRange, Range};
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+    #[test]
+    pub fn empty() {
+        assert_tokens(&Range::<i32>::empty().readable(), &[Token::Str("[]")]);
+        assert_tokens(
+            &Range::<i32>::empty().compact(),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let range = Range::composite(vec![Range::single(1), Range::from_exclusive(5)]);
+        assert_tokens(&range.clone().readable(), &[Token::Str("{1; (5..)}")]);
+        assert_tokens(
+            &range.compact(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Tuple { len: 2 },
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I32(1),
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I32(1),
+                Token::TupleEnd,
+                Token::Tuple { len: 2 },
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Excluded",
+                },
+                Token::I32(5),
+                Token::UnitVariant {
+                    name: "Bound",
+                    variant: "Unbounded",
+                },
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    pub fn composite_is_simplified() {
+        let range: Range<i32> = serde_json::from_str(r#""{[3..5]; [1..3)}""#).unwrap();
+        assert_eq!(range, Range::continuous(1, 5));
+
+        assert_de_tokens(
+            &Range::<i32>::empty().compact(),
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Tuple { len: 2 },
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I32(5),
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I32(1),
+                Token::TupleEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    pub fn json() {
+        let range = Range::composite(vec![Range::to_exclusive(0), Range::from(10)]);
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(json, r#""{(..0); [10..)}""#);
+        assert_eq!(serde_json::from_str::<Range<i64>>(&json).unwrap(), range);
+        assert_eq!(
+            serde_json::to_string(&Range::Continuous(ContinuousRange::single(1))).unwrap(),
+            r#""1""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Range<i64>>(r#""[1..5)""#).unwrap(),
+            Range::continuous_end_exclusive(1, 5)
+        );
+    }
+}
+
+mod test_relation {
+    use crate::RangesRelation;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    pub fn unit_variant() {
+        assert_tokens(
+            &RangesRelation::Meets,
+            &[Token::UnitVariant {
+                name: "RangesRelation",
+                variant: "Meets",
+            }],
+        );
+    }
+}
@@ -457,6 +457,11 @@ mod test_is_full {
 }
 
 mod test_composite_simplification {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
     use crate::{ContinuousRange, Range};
     use assert_matches::assert_matches;
 
@@ -483,4 +488,992 @@ mod test_composite_simplification {
         let r: Range<i32> = Range::composite(vec![(1..=5).into()]);
         assert_matches!(r, Range::Continuous(ContinuousRange::Inclusive(1, 5)));
     }
+
+    #[test]
+    pub fn sorted() {
+        let r: Range<i32> = Range::composite(vec![(10..20).into(), (..=0).into(), (5..=5).into()]);
+        assert_eq!(
+            r,
+            Range::Composite(vec![
+                ContinuousRange::To(0),
+                ContinuousRange::Single(5),
+                ContinuousRange::EndExclusive(10, 20)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn merge_overlapping() {
+        let r: Range<i32> = Range::composite(vec![(10..20).into(), (1..=5).into(), (3..15).into()]);
+        assert_matches!(r, Range::Continuous(ContinuousRange::EndExclusive(1, 20)));
+        let r: Range<i32> = Range::composite(vec![(1..=5).into(), (2..=3).into()]);
+        assert_matches!(r, Range::Continuous(ContinuousRange::Inclusive(1, 5)));
+    }
+
+    #[test]
+    pub fn merge_meets() {
+        let r: Range<i32> = Range::composite(vec![(5..=10).into(), (1..=5).into()]);
+        assert_matches!(r, Range::Continuous(ContinuousRange::Inclusive(1, 10)));
+    }
+
+    #[test]
+    pub fn merge_adjacent() {
+        let r: Range<i32> = Range::composite(vec![(5..=10).into(), (1..5).into()]);
+        assert_matches!(r, Range::Continuous(ContinuousRange::Inclusive(1, 10)));
+        let r: Range<i32> = Range::composite(vec![(..=0).into(), Range::from_exclusive(0)]);
+        assert_matches!(r, Range::Continuous(ContinuousRange::Full));
+    }
+
+    #[test]
+    pub fn not_adjacent() {
+        let r: Range<i32> = Range::composite(vec![(1..5).into(), Range::from_exclusive(5)]);
+        assert_eq!(
+            r,
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(1, 5),
+                ContinuousRange::FromExclusive(5)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn simplify_composite() {
+        let r: Range<i32> = Range::Composite(vec![
+            ContinuousRange::Inclusive(8, 10),
+            ContinuousRange::Inclusive(5, 1),
+            ContinuousRange::Inclusive(3, 3),
+            ContinuousRange::EndExclusive(0, 3),
+        ]);
+        assert_eq!(
+            r.simplify(),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(0, 3),
+                ContinuousRange::Inclusive(8, 10)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn union_equality() {
+        let a: Range<i32> = Range::continuous(1, 5).union(&Range::continuous(10, 20));
+        let b: Range<i32> = Range::continuous(10, 20)
+            .union(&Range::continuous(2, 4))
+            .union(&Range::continuous(1, 5));
+        assert_eq!(a, b);
+
+        let mut hasher_a = DefaultHasher::new();
+        a.hash(&mut hasher_a);
+        let mut hasher_b = DefaultHasher::new();
+        b.hash(&mut hasher_b);
+        assert_eq!(hasher_a.finish(), hasher_b.finish());
+    }
+}
+
+mod test_intersection {
+    use crate::{ContinuousRange, Range};
+
+    macro_rules! intersection {
+        ($a:expr, $b:expr, $c:expr) => {
+            assert_eq!($a.intersection(&$b), $c);
+        };
+    }
+
+    #[test]
+    pub fn empty() {
+        intersection!(
+            Range::<i32>::empty(),
+            Range::continuous(1, 5),
+            Range::empty()
+        );
+        intersection!(Range::continuous(1, 5), Range::empty(), Range::empty());
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::empty(),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn full() {
+        intersection!(
+            Range::<i32>::full(),
+            Range::continuous(1, 5),
+            Range::continuous(1, 5)
+        );
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::full(),
+            Range::composite(vec![(1..3).into(), (5..).into()])
+        );
+    }
+
+    #[test]
+    pub fn continuous_continuous() {
+        intersection!(
+            Range::continuous(1, 10),
+            Range::continuous(5, 20),
+            Range::continuous(5, 10)
+        );
+        intersection!(
+            Range::continuous(1, 10),
+            Range::continuous(15, 20),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn continuous_composite() {
+        intersection!(
+            Range::continuous(2, 6),
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(2, 3),
+                ContinuousRange::Inclusive(5, 6)
+            ])
+        );
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::continuous(2, 6),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(2, 3),
+                ContinuousRange::Inclusive(5, 6)
+            ])
+        );
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::continuous(10, 20),
+            Range::continuous(10, 20)
+        );
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::continuous_exclusive(3, 5),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn composite_composite() {
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..).into()]),
+            Range::composite(vec![(..=1).into(), (6..8).into()]),
+            Range::Composite(vec![
+                ContinuousRange::Single(1),
+                ContinuousRange::EndExclusive(6, 8)
+            ])
+        );
+        intersection!(
+            Range::composite(vec![(1..3).into(), (5..10).into()]),
+            Range::composite(vec![(20..30).into(), (40..50).into()]),
+            Range::empty()
+        );
+    }
+}
+
+mod test_difference {
+    use crate::{ContinuousRange, Range};
+
+    macro_rules! difference {
+        ($a:expr, $b:expr, $c:expr) => {
+            assert_eq!($a.difference(&$b), $c);
+        };
+    }
+
+    #[test]
+    pub fn empty() {
+        difference!(
+            Range::<i32>::empty(),
+            Range::continuous(1, 5),
+            Range::empty()
+        );
+        difference!(
+            Range::continuous(1, 5),
+            Range::empty(),
+            Range::continuous(1, 5)
+        );
+    }
+
+    #[test]
+    pub fn full() {
+        difference!(
+            Range::<i32>::full(),
+            Range::continuous(1, 5),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(1),
+                ContinuousRange::FromExclusive(5)
+            ])
+        );
+        difference!(Range::continuous(1, 5), Range::full(), Range::empty());
+    }
+
+    #[test]
+    pub fn continuous_continuous() {
+        difference!(
+            Range::continuous(0, 10),
+            Range::continuous(5, 20),
+            Range::continuous_end_exclusive(0, 5)
+        );
+        difference!(
+            Range::continuous(0, 10),
+            Range::continuous(20, 30),
+            Range::continuous(0, 10)
+        );
+        difference!(
+            Range::continuous(0, 10),
+            Range::continuous(0, 10),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn split() {
+        difference!(
+            Range::continuous(0, 10),
+            Range::continuous(3, 5),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 3),
+                ContinuousRange::StartExclusive(5, 10)
+            ])
+        );
+        difference!(
+            Range::continuous(0, 10),
+            Range::continuous_exclusive(0, 5),
+            Range::Composite(vec![
+                ContinuousRange::Single(0),
+                ContinuousRange::Inclusive(5, 10)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        difference!(
+            Range::composite(vec![(0..10).into(), (20..30).into()]),
+            Range::continuous(5, 25),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 5),
+                ContinuousRange::Exclusive(25, 30)
+            ])
+        );
+        difference!(
+            Range::continuous(0, 10),
+            Range::composite(vec![(2..4).into(), (6..8).into()]),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 2),
+                ContinuousRange::EndExclusive(4, 6),
+                ContinuousRange::Inclusive(8, 10)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn sub_operator() {
+        assert_eq!(
+            Range::continuous(0, 10) - Range::continuous(3, 5),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 3),
+                ContinuousRange::StartExclusive(5, 10)
+            ])
+        );
+    }
+}
+
+mod test_overlaps {
+    use crate::Range;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Range::<i32>::empty().overlaps(&Range::empty()), false);
+        assert_eq!(Range::<i32>::empty().overlaps(&Range::full()), false);
+        assert_eq!(Range::continuous(1, 5).overlaps(&Range::empty()), false);
+    }
+
+    #[test]
+    pub fn continuous() {
+        assert_eq!(
+            Range::continuous(1, 5).overlaps(&Range::continuous(5, 10)),
+            true
+        );
+        assert_eq!(
+            Range::continuous_end_exclusive(1, 5).overlaps(&Range::continuous(5, 10)),
+            false
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..).into()]);
+        assert_eq!(r.overlaps(&Range::continuous(2, 4)), true);
+        assert_eq!(Range::continuous(2, 4).overlaps(&r), true);
+        assert_eq!(r.overlaps(&Range::continuous(3, 4)), false);
+        assert_eq!(Range::continuous(3, 4).overlaps(&r), false);
+        assert_eq!(
+            r.overlaps(&Range::composite(vec![(..1).into(), (3..5).into()])),
+            false
+        );
+        assert_eq!(
+            r.overlaps(&Range::composite(vec![(..1).into(), (3..=5).into()])),
+            true
+        );
+    }
+}
+
+mod test_compare_bounds {
+    use crate::{Range, RangesRelation};
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(
+            Range::<i32>::empty().compare_bounds(&Range::empty()),
+            Some(RangesRelation::Equal)
+        );
+        assert_eq!(
+            Range::<i32>::empty().compare_bounds(&Range::continuous(1, 5)),
+            None
+        );
+    }
+
+    #[test]
+    pub fn continuous() {
+        assert_eq!(
+            Range::continuous(1, 5).compare_bounds(&Range::continuous(5, 10)),
+            Some(RangesRelation::Meets)
+        );
+        assert_eq!(
+            Range::continuous(1, 5).compare_bounds(&Range::continuous(6, 10)),
+            Some(RangesRelation::StrictlyBefore)
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..=2).into(), (5..=6).into()]);
+        assert_eq!(
+            r.compare_bounds(&Range::continuous(3, 4)),
+            Some(RangesRelation::StrictlyContains)
+        );
+        assert_eq!(
+            Range::continuous(3, 4).compare_bounds(&r),
+            Some(RangesRelation::IsStrictlyContained)
+        );
+        assert_eq!(
+            r.compare_bounds(&Range::continuous(1, 6)),
+            Some(RangesRelation::Equal)
+        );
+        assert_eq!(
+            r.compare_bounds(&Range::composite(vec![(6..=7).into(), (9..=10).into()])),
+            Some(RangesRelation::Meets)
+        );
+        assert_eq!(
+            r.compare_bounds(&Range::from(0)),
+            Some(RangesRelation::IsStrictlyContained)
+        );
+    }
+}
+
+mod test_hull {
+    use std::ops::Bound;
+
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        let r = Range::<i32>::empty();
+        assert_eq!(r.hull(), ContinuousRange::Empty);
+        assert_eq!(r.range_bounds(), None);
+        assert_eq!(r.start(), None);
+        assert_eq!(r.end(), None);
+    }
+
+    #[test]
+    pub fn continuous() {
+        let r = Range::continuous_start_exclusive(1, 5);
+        assert_eq!(r.hull(), ContinuousRange::StartExclusive(1, 5));
+        assert_eq!(
+            r.range_bounds(),
+            Some((Bound::Excluded(&1), Bound::Included(&5)))
+        );
+        assert_eq!(r.start(), Some(Bound::Excluded(&1)));
+        assert_eq!(r.end(), Some(Bound::Included(&5)));
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..).into()]);
+        assert_eq!(r.hull(), ContinuousRange::From(1));
+        assert_eq!(
+            r.range_bounds(),
+            Some((Bound::Included(&1), Bound::Unbounded))
+        );
+        assert_eq!(r.start(), Some(Bound::Included(&1)));
+        assert_eq!(r.end(), Some(Bound::Unbounded));
+
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..10).into()]);
+        assert_eq!(r.hull(), ContinuousRange::EndExclusive(1, 10));
+    }
+
+    #[test]
+    pub fn composite_unsorted() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(5, 10),
+            ContinuousRange::Inclusive(3, 1),
+            ContinuousRange::Exclusive(2, 3),
+        ]);
+        assert_eq!(r.hull(), ContinuousRange::StartExclusive(2, 10));
+    }
+}
+
+mod test_complement {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty_and_full() {
+        assert_eq!(Range::<i32>::empty().complement(), Range::full());
+        assert_eq!(Range::<i32>::full().complement(), Range::empty());
+    }
+
+    #[test]
+    pub fn continuous() {
+        assert_eq!(
+            Range::continuous_end_exclusive(0, 5).complement(),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(0),
+                ContinuousRange::From(5)
+            ])
+        );
+        assert_eq!(
+            Range::continuous_start_exclusive(0, 5).complement(),
+            Range::Composite(vec![
+                ContinuousRange::To(0),
+                ContinuousRange::FromExclusive(5)
+            ])
+        );
+        assert_eq!(
+            Range::single(3).complement(),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(3),
+                ContinuousRange::FromExclusive(3)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(Range::from(5).complement(), Range::to_exclusive(5));
+        assert_eq!(Range::from_exclusive(5).complement(), Range::to(5));
+        assert_eq!(Range::to(5).complement(), Range::from_exclusive(5));
+        assert_eq!(Range::to_exclusive(5).complement(), Range::from(5));
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..).into()]);
+        assert_eq!(
+            r.complement(),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(1),
+                ContinuousRange::EndExclusive(3, 5)
+            ])
+        );
+
+        let r: Range<_> = Range::composite(vec![
+            Range::continuous_end_exclusive(1, 5),
+            Range::continuous_start_exclusive(5, 10),
+        ]);
+        assert_eq!(
+            r.complement(),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(1),
+                ContinuousRange::Single(5),
+                ContinuousRange::FromExclusive(10)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn double_complement() {
+        let r: Range<_> = Range::composite(vec![(..=1).into(), (3..5).into(), (8..=9).into()]);
+        assert_eq!(r.complement().complement(), r);
+    }
+
+    #[test]
+    pub fn not_operator() {
+        assert_eq!(
+            !Range::continuous_end_exclusive(0, 5),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(0),
+                ContinuousRange::From(5)
+            ])
+        );
+    }
+}
+
+mod test_gaps {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..=7).into()]);
+        assert_eq!(
+            r.gaps(&ContinuousRange::inclusive(0, 10)),
+            Range::Composite(vec![
+                ContinuousRange::end_exclusive(0, 1),
+                ContinuousRange::end_exclusive(3, 5),
+                ContinuousRange::start_exclusive(7, 10),
+            ])
+        );
+        assert_eq!(
+            r.gaps(&ContinuousRange::inclusive(2, 6)),
+            Range::continuous_end_exclusive(3, 5)
+        );
+    }
+
+    #[test]
+    pub fn unsorted_and_overlapping() {
+        let r = Range::Composite(vec![
+            ContinuousRange::inclusive(12, 15),
+            ContinuousRange::end_exclusive(2, 10),
+            ContinuousRange::end_exclusive(4, 12),
+        ]);
+        assert_eq!(
+            r.gaps(&ContinuousRange::end_exclusive(0, 20)),
+            Range::Composite(vec![
+                ContinuousRange::end_exclusive(0, 2),
+                ContinuousRange::exclusive(15, 20),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn same_as_complement_intersection() {
+        let r: Range<_> = Range::composite(vec![(..=1).into(), (3..5).into(), (8..=9).into()]);
+        for within in [
+            ContinuousRange::full(),
+            ContinuousRange::inclusive(0, 10),
+            ContinuousRange::exclusive(1, 8),
+            ContinuousRange::from(4),
+            ContinuousRange::single(6),
+            ContinuousRange::single(4),
+        ] {
+            assert_eq!(
+                r.gaps(&within),
+                r.complement()
+                    .intersection(&Range::Continuous(within.clone())),
+                "{within:?}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(
+            Range::<i32>::empty().gaps(&ContinuousRange::inclusive(1, 5)),
+            Range::continuous(1, 5)
+        );
+        assert_eq!(Range::full().gaps(&ContinuousRange::to(5)), Range::empty());
+        assert_eq!(
+            Range::single(3).gaps(&ContinuousRange::empty()),
+            Range::empty()
+        );
+    }
+}
+
+mod test_symmetric_difference {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn full() {
+        assert_eq!(
+            Range::<i32>::full().symmetric_difference(&Range::continuous(1, 5)),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(1),
+                ContinuousRange::FromExclusive(5)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let a: Range<_> = Range::composite(vec![(0..10).into(), (20..30).into()]);
+        let b: Range<_> = Range::composite(vec![(5..25).into(), (40..50).into()]);
+        let expected = Range::Composite(vec![
+            ContinuousRange::EndExclusive(0, 5),
+            ContinuousRange::EndExclusive(10, 20),
+            ContinuousRange::EndExclusive(25, 30),
+            ContinuousRange::EndExclusive(40, 50),
+        ]);
+        assert_eq!(a.symmetric_difference(&b), expected);
+        assert_eq!(b.symmetric_difference(&a), expected);
+    }
+
+    #[test]
+    pub fn bitxor_operator() {
+        assert_eq!(
+            Range::continuous(0, 10) ^ Range::continuous(5, 15),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 5),
+                ContinuousRange::StartExclusive(10, 15)
+            ])
+        );
+    }
+}
+
+mod test_operators {
+    use crate::{ContinuousRange, Range};
+
+    fn a() -> Range<i32> {
+        Range::continuous(0, 10)
+    }
+
+    fn b() -> Range<i32> {
+        Range::continuous(5, 15)
+    }
+
+    #[test]
+    pub fn bitor() {
+        let expected = Range::continuous(0, 15);
+        assert_eq!(a() | b(), expected);
+        assert_eq!(a() | &b(), expected);
+        assert_eq!(&a() | b(), expected);
+        assert_eq!(&a() | &b(), expected);
+        assert_eq!(a() + &b(), expected);
+    }
+
+    #[test]
+    pub fn bitand() {
+        let expected = Range::continuous(5, 10);
+        assert_eq!(a() & b(), expected);
+        assert_eq!(a() & &b(), expected);
+        assert_eq!(&a() & b(), expected);
+        assert_eq!(&a() & &b(), expected);
+    }
+
+    #[test]
+    pub fn sub() {
+        let expected = Range::continuous_end_exclusive(0, 5);
+        assert_eq!(a() - b(), expected);
+        assert_eq!(a() - &b(), expected);
+        assert_eq!(&a() - b(), expected);
+        assert_eq!(&a() - &b(), expected);
+    }
+
+    #[test]
+    pub fn bitxor() {
+        let expected = Range::Composite(vec![
+            ContinuousRange::EndExclusive(0, 5),
+            ContinuousRange::StartExclusive(10, 15),
+        ]);
+        assert_eq!(a() ^ b(), expected);
+        assert_eq!(a() ^ &b(), expected);
+        assert_eq!(&a() ^ b(), expected);
+        assert_eq!(&a() ^ &b(), expected);
+    }
+
+    #[test]
+    pub fn not() {
+        let expected = Range::Composite(vec![
+            ContinuousRange::ToExclusive(0),
+            ContinuousRange::FromExclusive(10),
+        ]);
+        assert_eq!(!a(), expected);
+        assert_eq!(!&a(), expected);
+    }
+
+    #[test]
+    pub fn assign() {
+        let mut r = a();
+        r |= b();
+        assert_eq!(r, Range::continuous(0, 15));
+        r &= &a();
+        assert_eq!(r, Range::continuous(0, 10));
+        r -= b();
+        assert_eq!(r, Range::continuous_end_exclusive(0, 5));
+        r ^= &a();
+        assert_eq!(r, Range::continuous(5, 10));
+    }
+}
+
+mod test_canonicalize {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn continuous() {
+        assert_eq!(
+            Range::continuous(1, 5).canonicalize(),
+            Range::continuous_exclusive(0, 6).canonicalize()
+        );
+        assert_eq!(
+            Range::continuous(1, 5).canonicalize(),
+            Range::continuous_end_exclusive(1, 6)
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..=3).into(), (4..=6).into()]);
+        assert_eq!(
+            r,
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(1, 3),
+                ContinuousRange::Inclusive(4, 6)
+            ])
+        );
+        assert_eq!(r.canonicalize(), Range::continuous_end_exclusive(1, 7));
+
+        let r: Range<_> = Range::composite(vec![(1..=3).into(), (5..=6).into()]);
+        assert_eq!(
+            r.canonicalize(),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(1, 4),
+                ContinuousRange::EndExclusive(5, 7)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn canonicalize_mut() {
+        let mut r = Range::composite(vec![
+            Range::continuous_exclusive(0, 2),
+            Range::continuous_exclusive(1, 2),
+        ]);
+        r.canonicalize_mut();
+        assert_eq!(r, Range::single(1).canonicalize());
+    }
+}
+
+mod test_discrete_union {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn union_keeps_bounds_as_written() {
+        let r = Range::continuous(1, 3) | Range::continuous(4, 6);
+        assert_eq!(
+            r,
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(1, 3),
+                ContinuousRange::Inclusive(4, 6)
+            ])
+        );
+        assert_eq!(r == Range::continuous(1, 6), false);
+        assert_eq!(r.canonicalize(), Range::continuous(1, 6).canonicalize());
+    }
+
+    #[test]
+    pub fn merges_following_components() {
+        assert_eq!(
+            Range::continuous(1, 3).discrete_union(&Range::continuous(4, 6)),
+            Range::continuous_end_exclusive(1, 7)
+        );
+        assert_eq!(
+            Range::continuous(1, 3).discrete_union(&Range::continuous(5, 6)),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(1, 4),
+                ContinuousRange::EndExclusive(5, 7)
+            ])
+        );
+    }
+}
+
+mod test_discrete_eq {
+    use crate::Range;
+
+    #[test]
+    pub fn same_values() {
+        assert_eq!(
+            Range::continuous(1, 5) == Range::continuous_end_exclusive(1, 6),
+            false
+        );
+        assert_eq!(
+            Range::continuous(1, 5).discrete_eq(&Range::continuous_end_exclusive(1, 6)),
+            true
+        );
+        assert_eq!(
+            Range::composite(vec![Range::continuous(1, 2), Range::continuous(3, 5)])
+                .discrete_eq(&Range::continuous_exclusive(0, 6)),
+            true
+        );
+        assert_eq!(
+            Range::<i32>::empty().discrete_eq(&Range::continuous(5, 1)),
+            true
+        );
+    }
+
+    #[test]
+    pub fn different_values() {
+        assert_eq!(
+            Range::continuous(1, 5).discrete_eq(&Range::continuous_end_exclusive(1, 5)),
+            false
+        );
+        assert_eq!(Range::to(5).discrete_eq(&Range::to(6)), false);
+    }
+}
+
+mod test_iter {
+    use crate::Range;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Range::<i32>::empty().iter().next(), None);
+        assert_eq!(Range::<i32>::empty().iter().size_hint(), (0, Some(0)));
+        assert_eq!(Range::<i32>::empty().iter().len(), 0);
+    }
+
+    #[test]
+    pub fn continuous() {
+        let values: Vec<_> = Range::continuous_start_exclusive(1, 5).iter().collect();
+        assert_eq!(values, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..=6).into(), (10..11).into()]);
+        let values: Vec<_> = r.iter().collect();
+        assert_eq!(values, vec![1, 2, 5, 6, 10]);
+        let values: Vec<_> = r.iter().rev().collect();
+        assert_eq!(values, vec![10, 6, 5, 2, 1]);
+    }
+
+    #[test]
+    pub fn double_ended() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..=6).into()]);
+        let mut iter = r.iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(6));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    pub fn size_hint() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..=6).into(), (10..11).into()]);
+        let mut iter = r.iter();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        assert_eq!(iter.len(), 5);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.len(), 3);
+
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..).into()]);
+        assert_eq!(r.iter().size_hint(), (3, None));
+    }
+
+    #[test]
+    pub fn into_iterator() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..=6).into()]);
+        let mut values = vec![];
+        for value in &r {
+            values.push(value);
+        }
+        assert_eq!(values, r.into_iter().collect::<Vec<_>>());
+    }
+}
+
+mod test_count {
+    use crate::Range;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Range::<i32>::empty().count(), Some(0));
+    }
+
+    #[test]
+    pub fn continuous() {
+        assert_eq!(Range::continuous(1, 5).count(), Some(5));
+        assert_eq!(Range::<i32>::full().count(), None);
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..=6).into(), (10..11).into()]);
+        assert_eq!(r.count(), Some(5));
+        let r: Range<_> = Range::composite(vec![(1..3).into(), (5..).into()]);
+        assert_eq!(r.count(), None);
+        let r: Range<u128> = Range::composite(vec![(0..=1).into(), (3..=u128::MAX).into()]);
+        assert_eq!(r.count(), Some(u128::MAX));
+    }
+}
+
+mod test_measure {
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::Range;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Range::<f64>::empty().measure(), Some(0.0));
+    }
+
+    #[test]
+    pub fn composite() {
+        let r: Range<f64> = Range::composite(vec![(1.0..2.5).into(), (5.0..=6.0).into()]);
+        assert_eq!(r.measure(), Some(2.5));
+        let r: Range<f64> = Range::composite(vec![(1.0..2.5).into(), (5.0..).into()]);
+        assert_eq!(r.measure(), None);
+    }
+
+    #[test]
+    pub fn dates() {
+        let date = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let r: Range<_> = Range::composite(vec![
+            (date("2000-01-01T08:00:00Z")..date("2000-01-01T12:00:00Z")).into(),
+            (date("2000-01-01T14:00:00Z")..date("2000-01-01T18:30:00Z")).into(),
+        ]);
+        assert_eq!(r.measure(), Some(Duration::minutes(8 * 60 + 30)));
+    }
+}
+
+mod test_stdlib_conversions {
+    use std::{
+        convert::TryFrom,
+        ops::{self, Bound},
+    };
+
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn from_bounds_tuple() {
+        let r: Range<_> = (Bound::Excluded(1), Bound::Included(5)).into();
+        assert_eq!(r, Range::continuous_start_exclusive(1, 5));
+    }
+
+    #[test]
+    pub fn from_range_bounds() {
+        assert_eq!(
+            Range::from_range_bounds(1..5),
+            Range::continuous_end_exclusive(1, 5)
+        );
+        assert_eq!(
+            Range::from_range_bounds((Bound::Unbounded, Bound::Excluded(5))),
+            Range::to_exclusive(5)
+        );
+    }
+
+    #[test]
+    pub fn into_std_ranges() {
+        assert_eq!(
+            ops::Range::try_from(Range::continuous_end_exclusive(1, 5)),
+            Ok(1..5)
+        );
+        assert_eq!(ops::RangeFrom::try_from(Range::from(1)), Ok(1..));
+        assert_eq!(
+            <(Bound<_>, Bound<_>)>::try_from(Range::continuous_exclusive(1, 5)),
+            Ok((Bound::Excluded(1), Bound::Excluded(5)))
+        );
+    }
+
+    #[test]
+    pub fn into_std_ranges_single_component() {
+        let r = Range::Composite(vec![ContinuousRange::inclusive(1, 5)]);
+        assert_eq!(ops::RangeInclusive::try_from(r), Ok(1..=5));
+    }
+
+    #[test]
+    pub fn into_std_ranges_composite() {
+        let r = Range::composite(vec![Range::single(1), Range::single(5)]);
+        assert!(ops::RangeInclusive::try_from(r.clone()).is_err());
+        assert!(<(Bound<_>, Bound<_>)>::try_from(r).is_err());
+        assert!(ops::RangeFull::try_from(Range::<i32>::Composite(vec![])).is_err());
+    }
 }